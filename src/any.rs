@@ -0,0 +1,186 @@
+use super::broken::*;
+use super::circular::*;
+use super::convert::*;
+use super::decibel::*;
+use super::linear::*;
+use super::logarithmic::*;
+use super::pitch::*;
+use super::power::*;
+use super::psychoacoustic::*;
+use super::spline::*;
+use super::stepped::*;
+use super::symlog::*;
+use super::table::*;
+use super::*;
+
+/// A type erased scale. Any scale can be boxed into one, but the concrete type is lost, so it can neither be
+/// cloned nor compared. Use [`AnyScale`] if that is needed.
+pub type DynScale<N> = Box<dyn Scale<N>>;
+
+/// A scale of any of the kinds this crate provides, to be picked at runtime, e.g. from a configuration file.
+/// Unlike a [`DynScale`] it can be cloned, compared and debug printed.
+///
+/// Wrappers around other scales, like [`BipolarScale`](crate::prelude::BipolarScale) and
+/// [`RoundedScale`](crate::prelude::RoundedScale), are not part of it: they can wrap an `AnyScale` instead, and a
+/// rounded scale requires values that implement [`RoundFromFloat`](crate::prelude::RoundFromFloat).
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    Linear(LinearScale<N>),
+    Log(LogarithmicScale<N>),
+    Power(PowerScale<N>),
+    SymLog(SymLogScale<N>),
+    Circular(CircularScale<N>),
+    Mel(MelScale<N>),
+    Bark(BarkScale<N>),
+    Erb(ErbScale<N>),
+    Broken(BrokenScale<N>),
+    Spline(SplineScale<N>),
+    Table(TableScale<N>),
+    Stepped(SteppedScale<N>),
+    Pitch(PitchScale<N>),
+    Decibel(DecibelScale<N>),
+    Fader(FaderScale<N>),
+}
+
+macro_rules! any_scale {
+    ($($variant:ident($scale:ty)),*) => {
+        $(
+            impl<N> From<$scale> for AnyScale<N>
+            where
+                N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+            {
+                fn from(scale: $scale) -> AnyScale<N> {
+                    AnyScale::$variant(scale)
+                }
+            }
+        )*
+
+        impl<N> AnyScale<N>
+        where
+            N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        {
+            /// The wrapped scale as a trait object.
+            pub fn as_dyn(&self) -> &dyn Scale<N> {
+                match self {
+                    $(AnyScale::$variant(scale) => scale,)*
+                }
+            }
+        }
+    };
+}
+
+any_scale!(
+    Linear(LinearScale<N>),
+    Log(LogarithmicScale<N>),
+    Power(PowerScale<N>),
+    SymLog(SymLogScale<N>),
+    Circular(CircularScale<N>),
+    Mel(MelScale<N>),
+    Bark(BarkScale<N>),
+    Erb(ErbScale<N>),
+    Broken(BrokenScale<N>),
+    Spline(SplineScale<N>),
+    Table(TableScale<N>),
+    Stepped(SteppedScale<N>),
+    Pitch(PitchScale<N>),
+    Decibel(DecibelScale<N>),
+    Fader(FaderScale<N>)
+);
+
+impl<N> Scale<N> for AnyScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.as_dyn().to_relative(absolute)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.as_dyn().to_absolute(relative)
+    }
+
+    fn max(&self) -> N {
+        self.as_dyn().max()
+    }
+
+    fn min(&self) -> N {
+        self.as_dyn().min()
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        self.as_dyn()
+            .to_relative_delta(absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        self.as_dyn()
+            .to_absolute_delta(relative_delta, absolute_pos)
+    }
+
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        self.as_dyn().ticks(count)
+    }
+
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        self.as_dyn().to_relative_slice(absolute, relative)
+    }
+
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        self.as_dyn().to_absolute_slice(relative, absolute)
+    }
+
+    fn to_relative_in_place(&self, values: &mut [N]) {
+        self.as_dyn().to_relative_in_place(values)
+    }
+
+    fn to_absolute_in_place(&self, values: &mut [N]) {
+        self.as_dyn().to_absolute_in_place(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_dyn_scale() {
+        let scales: Vec<DynScale<f64>> = vec![
+            Box::new(LinearScale::new(0.0, 100.0)),
+            Box::new(LogarithmicScale::new(1.0, 100.0)),
+            Box::new(PowerScale::new(0.0, 100.0, 2.0)),
+        ];
+
+        assert_approx_eq!(scales[0].to_absolute(0.5), 50.0);
+        assert_approx_eq!(scales[1].to_absolute(0.5), 10.0);
+        assert_approx_eq!(scales[2].to_absolute(0.5), 25.0);
+
+        let conv = (&scales[0], &scales[1]);
+        assert_approx_eq!(conv.convert(50.0), 10.0);
+    }
+
+    #[test]
+    fn test_any_scale() {
+        let log = LogarithmicScale::new(20.0, 20_000.0);
+        let any: AnyScale<f64> = log.clone().into();
+
+        assert_eq!(any, AnyScale::Log(log.clone()));
+        assert_eq!(any.clone(), any);
+        assert_ne!(any, AnyScale::from(LinearScale::new(20.0, 20_000.0)));
+
+        assert_approx_eq!(any.to_relative(200.0), log.to_relative(200.0));
+        assert_approx_eq!(any.to_absolute(0.5), log.to_absolute(0.5));
+        assert_approx_eq!(any.min(), 20.0);
+        assert_approx_eq!(any.max(), 20_000.0);
+
+        let fader: AnyScale<f64> = FaderScale::console_fader().into();
+        assert_approx_eq!(fader.to_absolute(0.75), 1.0);
+
+        let conv = (LinearScale::new(0.0, 1.0), fader);
+        assert_approx_eq!(conv.convert(0.0), 0.0);
+    }
+}
@@ -0,0 +1,68 @@
+// Polynomial approximations of exp2 and log2 for audio rate modulation, where the exact functions are too expensive.
+// Arguments these approximations are not designed for (non-finite, non-positive, subnormal or huge values)
+// fall back to the exact functions.
+
+/// Approximates `2^x` with a relative error below 2e-7.
+pub(crate) fn fast_exp2(x: f64) -> f64 {
+    if !(-1000.0..=1000.0).contains(&x) {
+        return x.exp2();
+    }
+
+    // 2^x = 2^i * 2^f with |f| <= 0.5, where 2^f = e^(f * ln 2) is a degree 6 Taylor polynomial
+    let i = x.round();
+    let t = (x - i) * std::f64::consts::LN_2;
+    let p = 1.0
+        + t * (1.0
+            + t * (1.0 / 2.0
+                + t * (1.0 / 6.0 + t * (1.0 / 24.0 + t * (1.0 / 120.0 + t * (1.0 / 720.0))))));
+    f64::from_bits(((i as i64 + 1023) as u64) << 52) * p
+}
+
+/// Approximates `log2(x)` with an absolute error below 5e-8.
+pub(crate) fn fast_log2(x: f64) -> f64 {
+    if !(f64::MIN_POSITIVE..=f64::MAX).contains(&x) {
+        return x.log2();
+    }
+
+    // x = m * 2^e with m in [sqrt(0.5), sqrt(2)), log2(m) = 2 / ln 2 * atanh((m - 1) / (m + 1))
+    let bits = x.to_bits();
+    let mut e = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mut m = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | (1023 << 52));
+    if m > std::f64::consts::SQRT_2 {
+        m *= 0.5;
+        e += 1;
+    }
+    let s = (m - 1.0) / (m + 1.0);
+    let s2 = s * s;
+    let atanh = s * (1.0 + s2 * (1.0 / 3.0 + s2 * (1.0 / 5.0 + s2 * (1.0 / 7.0))));
+    e as f64 + atanh * (2.0 / std::f64::consts::LN_2)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_fast_exp2() {
+        for i in -40_000..=40_000 {
+            let x = i as f64 * 0.001;
+            let exact = x.exp2();
+            assert!(((fast_exp2(x) - exact) / exact).abs() < 2e-7, "{}", x);
+        }
+        assert_eq!(fast_exp2(0.0), 1.0);
+        assert_eq!(fast_exp2(f64::NEG_INFINITY), 0.0);
+        assert!(fast_exp2(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_fast_log2() {
+        for i in 1..=100_000 {
+            let x = i as f64 * 0.37;
+            assert!((fast_log2(x) - x.log2()).abs() < 5e-8, "{}", x);
+        }
+        assert_eq!(fast_log2(1.0), 0.0);
+        assert_eq!(fast_log2(0.0), f64::NEG_INFINITY);
+        assert!(fast_log2(-1.0).is_nan());
+    }
+}
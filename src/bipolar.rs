@@ -0,0 +1,212 @@
+use super::convert::*;
+use super::error::*;
+use super::linear::*;
+use super::*;
+
+/// A scale composed of two halves that meet at a centre value, which is always mapped to the relative value `0.5`
+/// regardless of whether it is the arithmetic midpoint of the range. Each half can follow its own curve, e.g. to get
+/// finer control over cuts than over boosts. An optional detent around `0.5` snaps to the centre value,
+/// as needed for pan, balance or EQ gain controls.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BipolarScale<L, U> {
+    lower: L,
+    upper: U,
+    detent: f64,
+}
+
+impl<N> BipolarScale<LinearScale<N>, LinearScale<N>>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Creates a bipolar scale with linear halves from `min` to `centre` and from `centre` to `max`.
+    pub fn linear(min: N, centre: N, max: N) -> BipolarScale<LinearScale<N>, LinearScale<N>> {
+        BipolarScale::new(
+            LinearScale::new(min, centre.clone()),
+            LinearScale::new(centre, max),
+        )
+    }
+}
+
+impl<L, U> BipolarScale<L, U> {
+    /// Creates a bipolar scale from a lower half ending at the centre value and an upper half starting at it.
+    pub fn new(lower: L, upper: U) -> BipolarScale<L, U> {
+        BipolarScale {
+            lower,
+            upper,
+            detent: 0.0,
+        }
+    }
+
+    /// Like [`BipolarScale::new`], but rejects halves that do not meet at the same centre value.
+    pub fn try_new<N>(lower: L, upper: U) -> Result<BipolarScale<L, U>, ScaleError>
+    where
+        N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+        L: Scale<N>,
+        U: Scale<N>,
+    {
+        if lower.max() == upper.min() {
+            Ok(BipolarScale::new(lower, upper))
+        } else {
+            Err(ScaleError::CentreMismatch)
+        }
+    }
+
+    /// Sets the width of the relative range around `0.5` within which the scale snaps to its centre value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width is rejected by [`BipolarScale::try_with_detent`].
+    pub fn with_detent(self, width: f64) -> BipolarScale<L, U> {
+        self.try_with_detent(width)
+            .unwrap_or_else(|error| panic!("invalid detent: {}", error))
+    }
+
+    /// Like [`BipolarScale::with_detent`], but rejects widths outside `0.0..1.0`.
+    pub fn try_with_detent(self, width: f64) -> Result<BipolarScale<L, U>, ScaleError> {
+        if (0.0..1.0).contains(&width) {
+            Ok(BipolarScale {
+                detent: width,
+                ..self
+            })
+        } else {
+            Err(ScaleError::InvalidDetent)
+        }
+    }
+
+    pub fn detent(&self) -> f64 {
+        self.detent
+    }
+
+    pub fn lower(&self) -> &L {
+        &self.lower
+    }
+
+    pub fn upper(&self) -> &U {
+        &self.upper
+    }
+
+    fn lower_end(&self) -> f64 {
+        0.5 - self.detent / 2.0
+    }
+
+    fn upper_start(&self) -> f64 {
+        0.5 + self.detent / 2.0
+    }
+}
+
+impl<N, L, U> Scale<N> for BipolarScale<L, U>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    L: Scale<N>,
+    U: Scale<N>,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let centre = self.lower.max();
+
+        if absolute < centre {
+            self.lower.to_relative(absolute) * self.lower_end()
+        } else if absolute > centre {
+            let upper_start = self.upper_start();
+            upper_start + self.upper.to_relative(absolute) * (1.0 - upper_start)
+        } else {
+            0.5
+        }
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let lower_end = self.lower_end();
+        let upper_start = self.upper_start();
+
+        if relative < lower_end {
+            self.lower.to_absolute(relative / lower_end)
+        } else if relative > upper_start {
+            self.upper
+                .to_absolute((relative - upper_start) / (1.0 - upper_start))
+        } else {
+            self.lower.max()
+        }
+    }
+
+    fn max(&self) -> N {
+        self.upper.max()
+    }
+
+    fn min(&self) -> N {
+        self.lower.min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_off_centre() {
+        let scale = BipolarScale::linear(-60_f64, 0.0, 12.0);
+
+        assert_approx_eq!(scale.to_absolute(0.0), -60.0);
+        assert_approx_eq!(scale.to_absolute(0.25), -30.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.75), 6.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 12.0);
+
+        assert_approx_eq!(scale.to_relative(-30.0), 0.25);
+        assert_approx_eq!(scale.to_relative(0.0), 0.5);
+        assert_approx_eq!(scale.to_relative(6.0), 0.75);
+
+        assert_approx_eq!(scale.min(), -60.0);
+        assert_approx_eq!(scale.max(), 12.0);
+    }
+
+    #[test]
+    fn test_detent() {
+        let scale = BipolarScale::linear(-100_f64, 0.0, 100.0).with_detent(0.1);
+
+        assert_approx_eq!(scale.to_absolute(0.46), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.54), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.0), -100.0);
+        assert_approx_eq!(scale.to_absolute(0.225), -50.0);
+        assert_approx_eq!(scale.to_absolute(0.775), 50.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 100.0);
+
+        assert_approx_eq!(scale.to_relative(0.0), 0.5);
+        assert_approx_eq!(scale.to_relative(-50.0), 0.225);
+        assert_approx_eq!(scale.to_relative(50.0), 0.775);
+    }
+
+    #[test]
+    fn test_different_curves() {
+        let scale = BipolarScale::new(
+            LinearScale::new(-1_f64, 0.0),
+            PowerScale::new(0.0, 100.0, 2.0),
+        );
+
+        assert_approx_eq!(scale.to_absolute(0.25), -0.5);
+        assert_approx_eq!(scale.to_absolute(0.75), 25.0);
+        assert_approx_eq!(scale.to_relative(25.0), 0.75);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid detent")]
+    fn test_with_detent_rejects_full_width() {
+        BipolarScale::linear(-1.0, 0.0, 1.0).with_detent(1.0);
+    }
+
+    #[test]
+    fn test_bipolar_try_new() {
+        assert!(
+            BipolarScale::try_new(LinearScale::new(-1.0, 0.0), LinearScale::new(0.0, 1.0)).is_ok()
+        );
+        assert_eq!(
+            BipolarScale::try_new(LinearScale::new(-1.0, 0.0), LinearScale::new(0.5, 1.0)),
+            Err(ScaleError::CentreMismatch)
+        );
+        assert_eq!(
+            BipolarScale::linear(-1.0, 0.0, 1.0).try_with_detent(1.0),
+            Err(ScaleError::InvalidDetent)
+        );
+    }
+}
@@ -1,14 +1,41 @@
 use super::convert::*;
+use super::error::*;
+use super::power::*;
 use super::*;
 use crate::linear::*;
 
+/// The shape of a single segment of a [`BrokenScale`] between two neighbouring breakpoints.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Curve {
+    /// Relative values are distributed linearly over the segment's absolute values.
+    Linear,
+    /// Relative values are distributed logarithmically over the segment's absolute values,
+    /// which must therefore be greater than zero. Like with a [`LogarithmicScale`](crate::prelude::LogarithmicScale),
+    /// absolute values that are not greater than zero, e.g. when extrapolating beyond the range of the scale,
+    /// convert to NaN; use [`to_clamped_relative`](Scale::to_clamped_relative) if that can happen.
+    Logarithmic,
+    /// Maps `relative^exponent` onto the segment, like a [`PowerScale`](crate::prelude::PowerScale).
+    Power(f64),
+    /// The absolute value holds at the start of the segment for its whole relative span and then jumps to the next breakpoint.
+    ///
+    /// Unlike the other curves a hold segment cannot be inverted exactly, since its whole relative span maps onto a
+    /// single absolute value: absolute values between its breakpoints convert to the relative value at its end, where
+    /// the jump happens, and converting the relative value back yields the absolute value of the next breakpoint.
+    Hold,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct BrokenScale<N>
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
 {
     delegate: LinearScale<N>,
+    // the absolute values of the steps as they were passed in, converting them back from `steps` is not exact
+    breakpoints: Vec<N>,
     steps: Vec<(f64, f64)>,
+    curves: Vec<Curve>,
 }
 
 impl<N> BrokenScale<N>
@@ -16,79 +43,162 @@ where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
 {
     pub fn new(min: N, max: N, steps: &[(N, f64)]) -> BrokenScale<N> {
+        BrokenScale::with_curves(min, max, steps, &[])
+    }
+
+    /// Creates a broken scale whose segments follow the provided curves. There is one segment more than there are steps,
+    /// the first one starting at `min` and the last one ending at `max`. Segments without a curve are linear.
+    pub fn with_curves(min: N, max: N, steps: &[(N, f64)], curves: &[Curve]) -> BrokenScale<N> {
         let delegate = LinearScale::new(min, max);
-        let steps = steps
+        let breakpoints = steps.iter().map(|(abs, _)| abs.clone()).collect();
+        let steps: Vec<(f64, f64)> = steps
             .iter()
             .map(|(abs, rel)| (delegate.to_relative(abs.clone()), *rel))
             .collect();
-        BrokenScale { delegate, steps }
+        let curves = (0..=steps.len())
+            .map(|i| curves.get(i).copied().unwrap_or(Curve::Linear))
+            .collect();
+        BrokenScale {
+            delegate,
+            breakpoints,
+            steps,
+            curves,
+        }
     }
 
-    fn broken_y(&self, rel_x: f64) -> f64 {
-        let mut from = (0.0, 0.0);
-        let mut to = (1.0, 1.0);
+    /// Like [`BrokenScale::new`], but rejects empty or non-finite ranges as well as steps that lie
+    /// outside the scale or are not strictly increasing in both their absolute and relative values.
+    pub fn try_new(min: N, max: N, steps: &[(N, f64)]) -> Result<BrokenScale<N>, ScaleError> {
+        check_range(min.clone().to_float(), max.clone().to_float())?;
+        let scale = BrokenScale::new(min, max, steps);
+        check_breakpoints(&scale.steps)?;
+        Ok(scale)
+    }
 
-        if rel_x >= 1.0 {
-            if let Some((x, y)) = self.steps.iter().last() {
-                from = (*x, *y);
-            }
-        } else {
-            let closed_steps = self.steps.iter().chain(std::iter::once(&(1.0, 1.0)));
+    /// Like [`BrokenScale::with_curves`], but additionally requires exactly one curve per segment,
+    /// logarithmic segments to be strictly positive and power segments to have a valid exponent.
+    pub fn try_with_curves(
+        min: N,
+        max: N,
+        steps: &[(N, f64)],
+        curves: &[Curve],
+    ) -> Result<BrokenScale<N>, ScaleError> {
+        if curves.len() != steps.len() + 1 {
+            return Err(ScaleError::SegmentCountMismatch {
+                expected: steps.len() + 1,
+                actual: curves.len(),
+            });
+        }
 
-            for (x, y) in closed_steps {
-                if x < &rel_x {
-                    from = (*x, *y);
-                } else {
-                    to = (*x, *y);
-                    break;
+        let scale = BrokenScale::try_new(min, max, steps)?;
+        for (index, curve) in curves.iter().enumerate() {
+            match curve {
+                Curve::Logarithmic => {
+                    let (from, to) = scale.segment(index);
+                    if scale.absolute(from.0) <= 0.0 || scale.absolute(to.0) <= 0.0 {
+                        return Err(ScaleError::NonPositiveLogBound);
+                    }
                 }
+                Curve::Power(exponent) => check_exponent(*exponent)?,
+                Curve::Linear | Curve::Hold => (),
             }
         }
 
-        // y = m * x + t
-        // m = dy/dx
-        // t = y - m * x
+        Ok(BrokenScale {
+            curves: curves.to_vec(),
+            ..scale
+        })
+    }
 
-        let dx = to.0 - from.0;
-        let dy = to.1 - from.1;
-        let m = dy / dx;
-        let t = from.1 - m * from.0;
+    /// The breakpoints of this scale as `(absolute, relative)` pairs, exactly as they were passed in.
+    pub fn steps(&self) -> Vec<(N, f64)> {
+        self.breakpoints
+            .iter()
+            .cloned()
+            .zip(self.steps.iter().map(|(_, y)| *y))
+            .collect()
+    }
 
-        m * rel_x + t
+    pub fn curves(&self) -> &[Curve] {
+        &self.curves
     }
 
-    fn broken_x(&self, rel_y: f64) -> f64 {
-        let mut from = (0.0, 0.0);
-        let mut to = (1.0, 1.0);
+    fn segment(&self, index: usize) -> ((f64, f64), (f64, f64)) {
+        let from = if index == 0 {
+            (0.0, 0.0)
+        } else {
+            self.steps[index - 1]
+        };
+        let to = self.steps.get(index).copied().unwrap_or((1.0, 1.0));
+        (from, to)
+    }
 
-        if rel_y >= 1.0 {
-            if let Some((x, y)) = self.steps.iter().last() {
-                from = (*x, *y);
+    fn absolute(&self, rel_x: f64) -> f64 {
+        let min = self.delegate.min().to_float();
+        let max = self.delegate.max().to_float();
+        min + rel_x * (max - min)
+    }
+
+    fn broken_y(&self, rel_x: f64) -> f64 {
+        let index = if rel_x >= 1.0 {
+            self.steps.len()
+        } else {
+            self.steps.iter().take_while(|(x, _)| x < &rel_x).count()
+        };
+
+        let (from, to) = self.segment(index);
+
+        // y = y0 + v * (y1 - y0), where v is the progress along the segment according to its curve
+
+        let v = match self.curves[index] {
+            Curve::Linear => (rel_x - from.0) / (to.0 - from.0),
+            Curve::Logarithmic => {
+                let a0 = self.absolute(from.0);
+                let a1 = self.absolute(to.0);
+                (self.absolute(rel_x) / a0).ln() / (a1 / a0).ln()
             }
+            Curve::Power(exponent) => {
+                signed_powf((rel_x - from.0) / (to.0 - from.0), 1.0 / exponent)
+            }
+            Curve::Hold => return if rel_x <= from.0 { from.1 } else { to.1 },
+        };
+
+        from.1 + v * (to.1 - from.1)
+    }
+
+    fn broken_x(&self, rel_y: f64) -> f64 {
+        let index = if rel_y >= 1.0 {
+            self.steps.len()
         } else {
-            let closed_steps = self.steps.iter().chain(std::iter::once(&(1.0, 1.0)));
+            self.steps.iter().take_while(|(_, y)| y < &rel_y).count()
+        };
+
+        let (from, to) = self.segment(index);
+
+        // v = (y - y0) / (y1 - y0)
+        // x = x0 + curve(v) * (x1 - x0)
 
-            for (x, y) in closed_steps {
-                if y < &rel_y {
-                    from = (*x, *y);
+        let v = (rel_y - from.1) / (to.1 - from.1);
+
+        match self.curves[index] {
+            Curve::Linear => from.0 + v * (to.0 - from.0),
+            Curve::Logarithmic => {
+                let min = self.delegate.min().to_float();
+                let max = self.delegate.max().to_float();
+                let a0 = self.absolute(from.0);
+                let a1 = self.absolute(to.0);
+                let a = a0 * (a1 / a0).powf(v);
+                (a - min) / (max - min)
+            }
+            Curve::Power(exponent) => from.0 + signed_powf(v, exponent) * (to.0 - from.0),
+            Curve::Hold => {
+                if rel_y < to.1 {
+                    from.0
                 } else {
-                    to = (*x, *y);
-                    break;
+                    to.0
                 }
             }
         }
-
-        // y = m * x + t
-        // m = dy/dx
-        // t = y - m * x
-        // x = (y - t) / m
-
-        let dx = to.0 - from.0;
-        let dy = to.1 - from.1;
-        let m = dy / dx;
-        let t = from.1 - m * from.0;
-
-        (rel_y - t) / m
     }
 }
 
@@ -97,7 +207,7 @@ where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
 {
     fn to_relative(&self, absolute: N) -> f64 {
-        let delegated_relative = dbg!(self.delegate.to_relative(absolute));
+        let delegated_relative = self.delegate.to_relative(absolute);
         self.broken_y(delegated_relative)
     }
 
@@ -113,9 +223,45 @@ where
     fn min(&self) -> N {
         self.delegate.min()
     }
+
+    /// Every breakpoint is a major tick, the ticks in between are distributed over the segments according to
+    /// their relative share of the scale and their curve.
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        let tolerance = 1e-9 * (self.absolute(1.0) - self.absolute(0.0)).abs();
+        let mut values = Vec::new();
+        let mut bounds = vec![self.absolute(0.0)];
+
+        for (index, curve) in self.curves.iter().enumerate() {
+            let (from, to) = self.segment(index);
+            let (from_abs, to_abs) = (self.absolute(from.0), self.absolute(to.0));
+            let target = (count as f64 * (to.1 - from.1)).round() as usize;
+            match curve {
+                Curve::Logarithmic => values.extend(decade_values(from_abs, to_abs, target)),
+                Curve::Hold => (),
+                Curve::Linear | Curve::Power(_) => {
+                    values.extend(nice_values(from_abs, to_abs, target))
+                }
+            }
+            bounds.push(to_abs);
+        }
+
+        // prefer round tick values over breakpoints that only differ from them by rounding errors
+        for bound in bounds {
+            match values
+                .iter_mut()
+                .find(|(value, _)| (value - bound).abs() <= tolerance)
+            {
+                Some(value) => value.1 = true,
+                None => values.push((bound, true)),
+            }
+        }
+
+        to_ticks(self, values)
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod test {
 
     use crate::prelude::*;
@@ -135,6 +281,172 @@ mod test {
         assert_approx_eq!(0.5, broken.to_relative(-54.0));
     }
 
+    #[test]
+    fn test_broken_scale_try_new() {
+        assert!(BrokenScale::try_new(-120_f64, 12_f64, &[(-60.0, 0.25), (-20.0, 0.5)]).is_ok());
+        assert_eq!(
+            BrokenScale::try_new(-120_f64, 12_f64, &[(-20.0, 0.25), (-60.0, 0.5)]),
+            Err(ScaleError::NonMonotonicBreakpoints { index: 1 })
+        );
+        assert_eq!(
+            BrokenScale::try_new(-120_f64, 12_f64, &[(-60.0, 0.5), (-20.0, 0.25)]),
+            Err(ScaleError::NonMonotonicBreakpoints { index: 1 })
+        );
+        assert_eq!(
+            BrokenScale::try_new(-120_f64, 12_f64, &[(24.0, 0.5)]),
+            Err(ScaleError::BreakpointOutOfRange { index: 0 })
+        );
+        assert_eq!(
+            BrokenScale::try_new(-120_f64, 12_f64, &[(-60.0, 1.5)]),
+            Err(ScaleError::BreakpointOutOfRange { index: 0 })
+        );
+        assert_eq!(
+            BrokenScale::try_new(12_f64, 12_f64, &[]),
+            Err(ScaleError::EmptyRange)
+        );
+    }
+
+    #[test]
+    fn test_broken_scale_curves() {
+        let broken = BrokenScale::with_curves(
+            20_f64,
+            20_000_f64,
+            &[(100.0, 0.2)],
+            &[Curve::Linear, Curve::Logarithmic],
+        );
+
+        assert_approx_eq!(20.0, broken.to_absolute(0.0));
+        assert_approx_eq!(60.0, broken.to_absolute(0.1));
+        assert_approx_eq!(100.0, broken.to_absolute(0.2));
+        assert_approx_eq!(1000.0, broken.to_absolute(0.2 + 0.8 / 200_f64.log10()));
+        assert_approx_eq!(20_000.0, broken.to_absolute(1.0));
+
+        assert_approx_eq!(0.0, broken.to_relative(20.0));
+        assert_approx_eq!(0.1, broken.to_relative(60.0));
+        assert_approx_eq!(0.2, broken.to_relative(100.0));
+        assert_approx_eq!(0.6, broken.to_relative(200_f64.sqrt() * 100.0));
+        assert_approx_eq!(1.0, broken.to_relative(20_000.0));
+    }
+
+    #[test]
+    fn test_broken_scale_power_curve() {
+        let broken = BrokenScale::with_curves(
+            0_f64,
+            200_f64,
+            &[(100.0, 0.5)],
+            &[Curve::Power(2.0), Curve::Linear],
+        );
+
+        assert_approx_eq!(25.0, broken.to_absolute(0.25));
+        assert_approx_eq!(0.25, broken.to_relative(25.0));
+        assert_approx_eq!(150.0, broken.to_absolute(0.75));
+        assert_approx_eq!(0.75, broken.to_relative(150.0));
+    }
+
+    #[test]
+    fn test_broken_scale_hold_curve() {
+        let broken = BrokenScale::with_curves(
+            0_f64,
+            100_f64,
+            &[(40.0, 0.4), (60.0, 0.6)],
+            &[Curve::Linear, Curve::Hold, Curve::Linear],
+        );
+
+        assert_approx_eq!(40.0, broken.to_absolute(0.4));
+        assert_approx_eq!(40.0, broken.to_absolute(0.5));
+        assert_approx_eq!(60.0, broken.to_absolute(0.6));
+        assert_approx_eq!(80.0, broken.to_absolute(0.8));
+
+        assert_approx_eq!(0.4, broken.to_relative(40.0));
+        assert_approx_eq!(0.6, broken.to_relative(50.0));
+        assert_approx_eq!(0.6, broken.to_relative(60.0));
+
+        // the hold segment collapses onto its breakpoints when converting back and forth
+        assert_approx_eq!(60.0, broken.to_absolute(broken.to_relative(50.0)));
+        assert_approx_eq!(0.4, broken.to_relative(broken.to_absolute(0.5)));
+        assert_approx_eq!(0.8, broken.to_relative(broken.to_absolute(0.8)));
+    }
+
+    #[test]
+    fn test_broken_scale_steps() {
+        let broken = BrokenScale::new(0_f64, 100_f64, &[(29.0, 0.5)]);
+        assert_eq!(broken.steps(), vec![(29.0, 0.5)]);
+
+        let broken = BrokenScale::new(0_i32, 100_i32, &[(29, 0.5), (71, 0.75)]);
+        assert_eq!(broken.steps(), vec![(29, 0.5), (71, 0.75)]);
+    }
+
+    #[test]
+    fn test_broken_scale_log_curve_extrapolation() {
+        let broken = BrokenScale::with_curves(
+            20_f64,
+            20_000_f64,
+            &[(2_000.0, 0.8)],
+            &[Curve::Logarithmic, Curve::Linear],
+        );
+
+        assert_approx_eq!(-0.4, broken.to_relative(2.0));
+        assert_approx_eq!(2.0, broken.to_absolute(-0.4));
+        assert!(broken.to_relative(-20.0).is_nan());
+        assert_approx_eq!(0.0, broken.to_clamped_relative(-20.0));
+    }
+
+    #[test]
+    fn test_broken_scale_curves_round_trip() {
+        let broken = BrokenScale::with_curves(
+            10_f64,
+            10_000_f64,
+            &[(100.0, 0.25), (1_000.0, 0.75)],
+            &[Curve::Power(0.5), Curve::Logarithmic, Curve::Power(3.0)],
+        );
+
+        for i in 0..=20 {
+            let relative = i as f64 * 0.05;
+            assert_approx_eq!(relative, broken.to_relative(broken.to_absolute(relative)));
+        }
+    }
+
+    #[test]
+    fn test_broken_scale_try_with_curves() {
+        assert!(BrokenScale::try_with_curves(
+            20_f64,
+            20_000_f64,
+            &[(100.0, 0.2)],
+            &[Curve::Linear, Curve::Logarithmic]
+        )
+        .is_ok());
+        assert_eq!(
+            BrokenScale::try_with_curves(
+                20_f64,
+                20_000_f64,
+                &[(100.0, 0.2)],
+                &[Curve::Logarithmic]
+            ),
+            Err(ScaleError::SegmentCountMismatch {
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            BrokenScale::try_with_curves(
+                -20_f64,
+                20_000_f64,
+                &[(100.0, 0.2)],
+                &[Curve::Logarithmic, Curve::Linear]
+            ),
+            Err(ScaleError::NonPositiveLogBound)
+        );
+        assert_eq!(
+            BrokenScale::try_with_curves(
+                -20_f64,
+                20_000_f64,
+                &[(100.0, 0.2)],
+                &[Curve::Linear, Curve::Power(-1.0)]
+            ),
+            Err(ScaleError::InvalidExponent)
+        );
+    }
+
     #[test]
     fn test_broken_scale_converter() {
         let broken = BrokenScale::new(-120_f64, 12_f64, &vec![]);
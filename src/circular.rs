@@ -0,0 +1,178 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// A linear scale for periodic quantities like angles, phases or hues, where `min` and `max` denote the same point.
+/// Values outside the range wrap around instead of being extrapolated, and deltas take the shortest path
+/// across the wrap point, e.g. moving from 359° to 1° is a change of 2° rather than -358°.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircularScale<N> {
+    min: N,
+    max: N,
+    min_f64: f64,
+    period: f64,
+    inverted: bool,
+}
+
+impl<N> CircularScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(min: N, max: N) -> CircularScale<N> {
+        CircularScale::create(min, max, false)
+    }
+
+    pub fn inverted(min: N, max: N) -> CircularScale<N> {
+        CircularScale::create(min, max, true)
+    }
+
+    /// Like [`CircularScale::new`], but rejects non-finite and empty ranges.
+    pub fn try_new(min: N, max: N) -> Result<CircularScale<N>, ScaleError> {
+        check_range(min.clone().to_float(), max.clone().to_float())?;
+        Ok(CircularScale::new(min, max))
+    }
+
+    /// Like [`CircularScale::inverted`], but rejects non-finite and empty ranges.
+    pub fn try_inverted(min: N, max: N) -> Result<CircularScale<N>, ScaleError> {
+        check_range(min.clone().to_float(), max.clone().to_float())?;
+        Ok(CircularScale::inverted(min, max))
+    }
+
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    fn create(min: N, max: N, inverted: bool) -> CircularScale<N> {
+        let min_f64 = min.clone().to_float();
+        let max_f64 = max.clone().to_float();
+
+        CircularScale {
+            min,
+            max,
+            min_f64,
+            period: max_f64 - min_f64,
+            inverted,
+        }
+    }
+
+    fn direction(&self) -> f64 {
+        if self.inverted {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+}
+
+impl<N> Scale<N> for CircularScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let relative = wrap((absolute.to_float() - self.min_f64) / self.period);
+
+        if self.inverted {
+            1.0 - relative
+        } else {
+            relative
+        }
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let relative = wrap(relative);
+        let relative = if self.inverted {
+            1.0 - relative
+        } else {
+            relative
+        };
+
+        N::from_float(self.min_f64 + relative * self.period)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, _relative_pos: f64) -> f64 {
+        shortest(self.direction() * absolute_delta.to_float() / self.period)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, _absolute_pos: N) -> N {
+        N::from_float(self.direction() * shortest(relative_delta) * self.period)
+    }
+}
+
+// values within 0.0..=1.0 are left untouched so that both ends of the range remain reachable
+fn wrap(relative: f64) -> f64 {
+    if (0.0..=1.0).contains(&relative) {
+        relative
+    } else {
+        relative.rem_euclid(1.0)
+    }
+}
+
+// folds a relative delta into -0.5..=0.5
+fn shortest(relative_delta: f64) -> f64 {
+    relative_delta - relative_delta.round()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_circular_wraps() {
+        let scale: CircularScale<f64> = CircularScale::new(0.0, 360.0);
+
+        assert_approx_eq!(scale.to_relative(90.0), 0.25);
+        assert_approx_eq!(scale.to_relative(360.0), 1.0);
+        assert_approx_eq!(scale.to_relative(450.0), 0.25);
+        assert_approx_eq!(scale.to_relative(-90.0), 0.75);
+
+        assert_approx_eq!(scale.to_absolute(0.0), 0.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 360.0);
+        assert_approx_eq!(scale.to_absolute(1.25), 90.0);
+        assert_approx_eq!(scale.to_absolute(-0.25), 270.0);
+    }
+
+    #[test]
+    fn test_circular_deltas() {
+        let scale: CircularScale<f64> = CircularScale::new(0.0, 360.0);
+
+        assert_approx_eq!(scale.to_relative_delta(2.0, 359.0 / 360.0), 2.0 / 360.0);
+        assert_approx_eq!(scale.to_relative_delta(-2.0, 1.0 / 360.0), -2.0 / 360.0);
+        assert_approx_eq!(scale.to_relative_delta(350.0, 0.5), -10.0 / 360.0);
+
+        assert_approx_eq!(scale.to_absolute_delta(0.01, 359.0), 3.6);
+        assert_approx_eq!(scale.to_absolute_delta(0.99, 1.0), -3.6);
+
+        let by_ref = &scale;
+        assert_approx_eq!(by_ref.to_relative_delta(2.0, 359.0 / 360.0), 2.0 / 360.0);
+    }
+
+    #[test]
+    fn test_circular_radians() {
+        use std::f64::consts::PI;
+
+        let scale: CircularScale<f64> = CircularScale::inverted(-PI, PI);
+        assert_approx_eq!(scale.to_relative(PI / 2.0), 0.25);
+        assert_approx_eq!(scale.to_absolute(0.25), PI / 2.0);
+        assert_approx_eq!(scale.to_absolute_delta(0.25, 0.0), -PI / 2.0);
+        assert_approx_eq!(scale.to_relative_delta(-PI / 2.0, 0.5), 0.25);
+    }
+
+    #[test]
+    fn test_circular_try_new() {
+        assert!(CircularScale::try_new(0.0, 360.0).is_ok());
+        assert_eq!(
+            CircularScale::try_inverted(0.0, 0.0),
+            Err(ScaleError::EmptyRange)
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use super::error::*;
+
 /// Something that can be converted to a floating point number.
 pub trait ToFloat<F> {
     /// Convert self into a floating point number.
@@ -16,6 +18,72 @@ pub trait FromFloat<F> {
     fn from_float(f: F) -> Self;
 }
 
+/// Something a floating point number can be converted into after rounding it according to a [`Rounding`] mode.
+/// Unlike [`FromFloat`], which simply truncates, this allows integral values to land on the nearest step.
+pub trait RoundFromFloat: FromFloat<f64> + Sized {
+    /// The smallest and largest floating point number that can be converted into the implementing type
+    /// without saturating.
+    fn bounds() -> (f64, f64);
+
+    /// Round the provided floating point number and convert it, saturating at the bounds of the implementing type.
+    fn round_from_float(f: f64, rounding: Rounding) -> Self {
+        Self::from_float(rounding.apply(f))
+    }
+
+    /// Round the provided floating point number and convert it,
+    /// failing if the result is NaN or lies outside the bounds of the implementing type.
+    fn checked_from_float(f: f64, rounding: Rounding) -> Result<Self, ScaleError> {
+        let rounded = rounding.apply(f);
+        let (min, max) = Self::bounds();
+        if rounded.is_nan() || rounded < min || rounded > max {
+            Err(ScaleError::NotRepresentable { value: f })
+        } else {
+            Ok(Self::from_float(rounded))
+        }
+    }
+
+    /// Convert the provided floating point number into the nearest value of the implementing type, rounding it
+    /// half to even if the type is integral, and fail if it is NaN or lies outside the bounds of the type.
+    fn nearest_from_float(f: f64) -> Result<Self, ScaleError> {
+        Self::checked_from_float(f, Rounding::HalfEven)
+    }
+}
+
+/// The ways a floating point number can be rounded to an integral value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Rounding {
+    /// Round towards zero, which is what a plain `as` cast does.
+    Truncate,
+    /// Round to the nearest integer, ties to the even one.
+    HalfEven,
+    /// Round to the nearest integer, ties away from zero.
+    HalfAwayFromZero,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+}
+
+impl Rounding {
+    pub fn apply(self, f: f64) -> f64 {
+        match self {
+            Rounding::Truncate => f.trunc(),
+            Rounding::HalfEven => {
+                if (f - f.trunc()).abs() == 0.5 {
+                    2.0 * (f / 2.0).round()
+                } else {
+                    f.round()
+                }
+            }
+            Rounding::HalfAwayFromZero => f.round(),
+            Rounding::Floor => f.floor(),
+            Rounding::Ceil => f.ceil(),
+        }
+    }
+}
+
 /// Something an integral number can be converted into.
 pub trait FromInt<I> {
     /// Convert the provided integral number into an instance of the implementing type.
@@ -209,6 +277,92 @@ impl FromFloat<f32> for usize {
     }
 }
 
+impl RoundFromFloat for f64 {
+    fn bounds() -> (f64, f64) {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    fn nearest_from_float(f: f64) -> Result<Self, ScaleError> {
+        Self::checked_from_float(f, Rounding::Truncate).map(|_| f)
+    }
+}
+impl RoundFromFloat for f32 {
+    fn bounds() -> (f64, f64) {
+        (f32::MIN as f64, f32::MAX as f64)
+    }
+
+    fn nearest_from_float(f: f64) -> Result<Self, ScaleError> {
+        Self::checked_from_float(f, Rounding::Truncate).map(|_| f as f32)
+    }
+}
+
+impl RoundFromFloat for i128 {
+    fn bounds() -> (f64, f64) {
+        (i128::MIN as f64, below_power_of_two(i128::MAX))
+    }
+}
+impl RoundFromFloat for i64 {
+    fn bounds() -> (f64, f64) {
+        (i64::MIN as f64, below_power_of_two(i64::MAX))
+    }
+}
+impl RoundFromFloat for i32 {
+    fn bounds() -> (f64, f64) {
+        (i32::MIN as f64, i32::MAX as f64)
+    }
+}
+impl RoundFromFloat for i16 {
+    fn bounds() -> (f64, f64) {
+        (i16::MIN as f64, i16::MAX as f64)
+    }
+}
+impl RoundFromFloat for i8 {
+    fn bounds() -> (f64, f64) {
+        (i8::MIN as f64, i8::MAX as f64)
+    }
+}
+impl RoundFromFloat for u128 {
+    fn bounds() -> (f64, f64) {
+        (0.0, below_power_of_two(u128::MAX))
+    }
+}
+impl RoundFromFloat for u64 {
+    fn bounds() -> (f64, f64) {
+        (0.0, below_power_of_two(u64::MAX))
+    }
+}
+impl RoundFromFloat for u32 {
+    fn bounds() -> (f64, f64) {
+        (0.0, u32::MAX as f64)
+    }
+}
+impl RoundFromFloat for u16 {
+    fn bounds() -> (f64, f64) {
+        (0.0, u16::MAX as f64)
+    }
+}
+impl RoundFromFloat for u8 {
+    fn bounds() -> (f64, f64) {
+        (0.0, u8::MAX as f64)
+    }
+}
+impl RoundFromFloat for usize {
+    fn bounds() -> (f64, f64) {
+        match std::mem::size_of::<usize>() {
+            8 => u64::bounds(),
+            4 => u32::bounds(),
+            _ => u16::bounds(),
+        }
+    }
+}
+
+// The maximum of a 64 or 128 bit integer is one below a power of two that is the nearest floating point number to it,
+// so the largest convertible floating point number is the one right below that power of two.
+fn below_power_of_two<I: ToFloat<f64>>(max: I) -> f64 {
+    let power_of_two: f64 = max.to_float();
+    f64::from_bits(power_of_two.to_bits() - 1)
+}
+
 impl FromInt<i128> for f64 {
     fn from_int(i: i128) -> Self {
         i as f64
@@ -329,3 +483,103 @@ impl FromInt<usize> for f32 {
         i as f32
     }
 }
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_rounding_modes() {
+        assert_eq!(Rounding::Truncate.apply(2.5), 2.0);
+        assert_eq!(Rounding::Truncate.apply(-2.7), -2.0);
+        assert_eq!(Rounding::HalfEven.apply(2.5), 2.0);
+        assert_eq!(Rounding::HalfEven.apply(3.5), 4.0);
+        assert_eq!(Rounding::HalfEven.apply(-2.5), -2.0);
+        assert_eq!(Rounding::HalfEven.apply(-3.5), -4.0);
+        assert_eq!(Rounding::HalfEven.apply(2.6), 3.0);
+        assert_eq!(Rounding::HalfEven.apply(-0.4), 0.0);
+        assert_eq!(
+            Rounding::HalfEven.apply(4_503_599_627_370_497.0),
+            4_503_599_627_370_497.0
+        );
+        assert_eq!(Rounding::HalfAwayFromZero.apply(2.5), 3.0);
+        assert_eq!(Rounding::HalfAwayFromZero.apply(-2.5), -3.0);
+        assert_eq!(Rounding::Floor.apply(-2.1), -3.0);
+        assert_eq!(Rounding::Ceil.apply(2.1), 3.0);
+    }
+
+    #[test]
+    fn test_round_from_float() {
+        assert_eq!(usize::round_from_float(0.999, Rounding::HalfEven), 1);
+        assert_eq!(usize::round_from_float(0.999, Rounding::Truncate), 0);
+        assert_eq!(u8::round_from_float(-3.0, Rounding::HalfEven), 0);
+        assert_eq!(u8::round_from_float(300.0, Rounding::HalfEven), 255);
+        assert_eq!(i32::round_from_float(-2.5, Rounding::HalfAwayFromZero), -3);
+    }
+
+    #[test]
+    fn test_checked_from_float() {
+        assert_eq!(u8::checked_from_float(254.6, Rounding::HalfEven), Ok(255));
+        assert_eq!(
+            u8::checked_from_float(255.6, Rounding::HalfEven),
+            Err(ScaleError::NotRepresentable { value: 255.6 })
+        );
+        assert_eq!(
+            usize::checked_from_float(-0.6, Rounding::HalfEven),
+            Err(ScaleError::NotRepresentable { value: -0.6 })
+        );
+        assert_eq!(usize::checked_from_float(-0.4, Rounding::HalfEven), Ok(0));
+        assert!(i64::checked_from_float(f64::NAN, Rounding::Floor).is_err());
+        assert_eq!(f64::checked_from_float(2.4, Rounding::Ceil), Ok(3.0));
+    }
+
+    #[test]
+    fn test_nearest_from_float() {
+        assert_eq!(usize::nearest_from_float(0.6), Ok(1));
+        assert_eq!(i32::nearest_from_float(-2.5), Ok(-2));
+        assert_eq!(f64::nearest_from_float(2.6), Ok(2.6));
+        assert_eq!(f32::nearest_from_float(2.5), Ok(2.5));
+        assert!(u8::nearest_from_float(255.6).is_err());
+        assert!(f32::nearest_from_float(1e39).is_err());
+        assert!(f64::nearest_from_float(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_checked_from_float_at_type_limits() {
+        let two_pow_63 = 9_223_372_036_854_775_808.0;
+        let largest_below = 9_223_372_036_854_774_784.0;
+        assert_eq!(
+            i64::checked_from_float(two_pow_63, Rounding::Truncate),
+            Err(ScaleError::NotRepresentable { value: two_pow_63 })
+        );
+        assert_eq!(
+            i64::checked_from_float(largest_below, Rounding::Truncate),
+            Ok(9_223_372_036_854_774_784)
+        );
+        assert_eq!(
+            i64::checked_from_float(-two_pow_63, Rounding::Truncate),
+            Ok(i64::MIN)
+        );
+        assert!(u64::checked_from_float(2.0 * two_pow_63, Rounding::Floor).is_err());
+        assert!(u64::checked_from_float(largest_below, Rounding::Floor).is_ok());
+        assert!(usize::checked_from_float(usize::MAX as f64, Rounding::Floor).is_err());
+        assert!(i128::checked_from_float(i128::MAX as f64, Rounding::Floor).is_err());
+        assert!(u128::checked_from_float(u128::MAX as f64, Rounding::Floor).is_err());
+        assert_eq!(
+            i32::checked_from_float(2_147_483_647.0, Rounding::Floor),
+            Ok(i32::MAX)
+        );
+
+        assert_eq!(
+            f32::checked_from_float(1e300, Rounding::Truncate),
+            Err(ScaleError::NotRepresentable { value: 1e300 })
+        );
+        assert!(f32::checked_from_float(f64::INFINITY, Rounding::Truncate).is_err());
+        assert_eq!(f32::checked_from_float(1e30, Rounding::Truncate), Ok(1e30));
+        assert_eq!(
+            f64::checked_from_float(f64::INFINITY, Rounding::Truncate),
+            Ok(f64::INFINITY)
+        );
+    }
+}
@@ -3,6 +3,9 @@ use super::Scale;
 use std::cmp::Ordering;
 use std::ops::*;
 
+// The number of relative values buffered at once by the slice conversions of scale pairs and smoothed values.
+pub(crate) const SLICE_CHUNK: usize = 256;
+
 pub trait Converter<E, I>
 where
     E: Sub<Output = E> + Add<Output = E> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
@@ -13,14 +16,36 @@ where
 
     fn add_external(&self, external_delta: E, internal_value: I) -> I {
         let external_value = self.convert_back(internal_value);
-        let new_internal_value = self.convert(external_value + external_delta);
-        new_internal_value
+        self.convert(external_value + external_delta)
     }
 
     fn add_internal(&self, internal_delta: I, external_value: E) -> E {
         let internal_value = self.convert(external_value);
-        let new_external_value = self.convert_back(internal_value + internal_delta);
-        new_external_value
+        self.convert_back(internal_value + internal_delta)
+    }
+
+    /// Converts a whole buffer of external values. Panics if the slices differ in length.
+    fn convert_slice(&self, external_values: &[E], internal_values: &mut [I]) {
+        assert_eq!(
+            external_values.len(),
+            internal_values.len(),
+            "slice lengths differ"
+        );
+        for (external, internal) in external_values.iter().zip(internal_values) {
+            *internal = self.convert(external.clone());
+        }
+    }
+
+    /// Converts a whole buffer of internal values back. Panics if the slices differ in length.
+    fn convert_back_slice(&self, internal_values: &[I], external_values: &mut [E]) {
+        assert_eq!(
+            internal_values.len(),
+            external_values.len(),
+            "slice lengths differ"
+        );
+        for (internal, external) in internal_values.iter().zip(external_values) {
+            *external = self.convert_back(internal.clone());
+        }
     }
 }
 
@@ -83,6 +108,40 @@ where
         let rel = internal.to_relative(internal_value);
         external.to_absolute(rel)
     }
+
+    fn convert_slice(&self, external_values: &[E], internal_values: &mut [I]) {
+        assert_eq!(
+            external_values.len(),
+            internal_values.len(),
+            "slice lengths differ"
+        );
+        let mut rel = [0.0; SLICE_CHUNK];
+        for (external, internal) in external_values
+            .chunks(SLICE_CHUNK)
+            .zip(internal_values.chunks_mut(SLICE_CHUNK))
+        {
+            let rel = &mut rel[..external.len()];
+            self.0.to_relative_slice(external, rel);
+            self.1.to_absolute_slice(rel, internal);
+        }
+    }
+
+    fn convert_back_slice(&self, internal_values: &[I], external_values: &mut [E]) {
+        assert_eq!(
+            internal_values.len(),
+            external_values.len(),
+            "slice lengths differ"
+        );
+        let mut rel = [0.0; SLICE_CHUNK];
+        for (internal, external) in internal_values
+            .chunks(SLICE_CHUNK)
+            .zip(external_values.chunks_mut(SLICE_CHUNK))
+        {
+            let rel = &mut rel[..internal.len()];
+            self.1.to_relative_slice(internal, rel);
+            self.0.to_absolute_slice(rel, external);
+        }
+    }
 }
 
 impl<E, I, SE, SI> ClampingConverter<E, I> for (SE, SI)
@@ -124,6 +183,26 @@ mod test {
         assert_approx_eq!((lin, log).convert(100.0), 24_000f64);
     }
 
+    #[test]
+    fn test_convert_slice() {
+        let lin = LinearScale::new(0.0, 100.0);
+        let log = LogarithmicScale::new(20.0, 24_000.0);
+        let conv = (&lin, &log);
+
+        let external: Vec<f64> = (0..1000).map(|i| i as f64 * 0.1).collect();
+        let mut internal = vec![0.0; external.len()];
+        conv.convert_slice(&external, &mut internal);
+        for (external, internal) in external.iter().zip(&internal) {
+            assert_eq!(*internal, conv.convert(*external));
+        }
+
+        let mut back = vec![0.0; internal.len()];
+        conv.convert_back_slice(&internal, &mut back);
+        for (external, back) in external.iter().zip(&back) {
+            assert_approx_eq!(*back, *external, 1e-9);
+        }
+    }
+
     #[test]
     fn example_from_readme() {
         let slider = Slider;
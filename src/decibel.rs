@@ -0,0 +1,428 @@
+use super::broken::*;
+use super::convert::*;
+use super::error::*;
+use super::linear::*;
+use super::*;
+
+/// The lower end of the decibel range that is used when a range is given as starting at negative infinity.
+pub const SILENCE_FLOOR_DB: f64 = -120.0;
+
+/// The kind of quantity a decibel value refers to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DecibelUnit {
+    /// Field quantities like amplitude or gain, i.e. `20 * log10(gain)`.
+    Amplitude,
+    /// Power quantities like energy or intensity, i.e. `10 * log10(power)`.
+    Power,
+}
+
+impl DecibelUnit {
+    /// Converts a linear gain (or power ratio) to decibels. Zero and negative values yield negative infinity.
+    pub fn to_db(self, linear: f64) -> f64 {
+        if linear <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            self.factor() * linear.log10()
+        }
+    }
+
+    /// Converts decibels to a linear gain (or power ratio). Negative infinity yields `0.0`.
+    pub fn from_db(self, db: f64) -> f64 {
+        10f64.powf(db / self.factor())
+    }
+
+    fn factor(self) -> f64 {
+        match self {
+            DecibelUnit::Amplitude => 20.0,
+            DecibelUnit::Power => 10.0,
+        }
+    }
+}
+
+/// A scale whose absolute values are linear gains (or power ratios) and whose relative values are distributed
+/// linearly over a range of decibels. Unlike a [`LogarithmicScale`](crate::prelude::LogarithmicScale) it has an
+/// explicit point of silence: the relative value `0.0` corresponds to a gain of `0.0` (negative infinity dB) and
+/// all gains at or below the lower end of the decibel range map to `0.0`.
+///
+/// By default decibels are distributed linearly, other distributions can be achieved by providing a different
+/// scale for the decibel range, as is done for [`FaderScale`].
+///
+/// A decibel range may start at negative infinity, in which case decibels are distributed from
+/// [`SILENCE_FLOOR_DB`] upwards and [`DecibelScale::min_db`] keeps reporting negative infinity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecibelScale<N, D = LinearScale<f64>> {
+    max: N,
+    unit: DecibelUnit,
+    db_delegate: D,
+    // the range was given as starting at negative infinity, the delegate starts at the silence floor
+    from_silence: bool,
+}
+
+/// A [`DecibelScale`] that models the law of a mixing console fader by distributing decibels along the
+/// breakpoints of a [`BrokenScale`](crate::prelude::BrokenScale), which makes each segment logarithmic in gain.
+pub type FaderScale<N> = DecibelScale<N, BrokenScale<f64>>;
+
+impl<N> DecibelScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Creates a scale for amplitudes that spans the given range of decibels above the point of silence.
+    pub fn new(min_db: f64, max_db: f64) -> DecibelScale<N> {
+        DecibelScale::create(min_db, max_db, DecibelUnit::Amplitude)
+    }
+
+    /// Creates a scale for power ratios that spans the given range of decibels above the point of silence.
+    pub fn power(min_db: f64, max_db: f64) -> DecibelScale<N> {
+        DecibelScale::create(min_db, max_db, DecibelUnit::Power)
+    }
+
+    /// Like [`DecibelScale::new`], but rejects empty, descending and non-finite decibel ranges other than those
+    /// starting at negative infinity.
+    pub fn try_new(min_db: f64, max_db: f64) -> Result<DecibelScale<N>, ScaleError> {
+        check_ascending_range(floor_db(min_db), max_db)?;
+        Ok(DecibelScale::new(min_db, max_db))
+    }
+
+    /// Like [`DecibelScale::power`], but rejects empty, descending and non-finite decibel ranges other than those
+    /// starting at negative infinity.
+    pub fn try_power(min_db: f64, max_db: f64) -> Result<DecibelScale<N>, ScaleError> {
+        check_ascending_range(floor_db(min_db), max_db)?;
+        Ok(DecibelScale::power(min_db, max_db))
+    }
+
+    fn create(min_db: f64, max_db: f64, unit: DecibelUnit) -> DecibelScale<N> {
+        DecibelScale::with_delegate(LinearScale::new(floor_db(min_db), max_db), unit)
+            .starting_at(min_db)
+    }
+}
+
+impl<N> FaderScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Creates a fader law for amplitudes from a decibel range and breakpoints of the form `(dB, relative position)`.
+    /// The bottom of the fader (relative `0.0`) is always silence.
+    pub fn fader(min_db: f64, max_db: f64, steps: &[(f64, f64)]) -> FaderScale<N> {
+        DecibelScale::with_delegate(
+            BrokenScale::new(floor_db(min_db), max_db, steps),
+            DecibelUnit::Amplitude,
+        )
+        .starting_at(min_db)
+    }
+
+    /// Like [`FaderScale::fader`], but rejects invalid decibel ranges and breakpoints.
+    pub fn try_fader(
+        min_db: f64,
+        max_db: f64,
+        steps: &[(f64, f64)],
+    ) -> Result<FaderScale<N>, ScaleError> {
+        check_ascending_range(floor_db(min_db), max_db)?;
+        Ok(DecibelScale::with_delegate(
+            BrokenScale::try_new(floor_db(min_db), max_db, steps)?,
+            DecibelUnit::Amplitude,
+        )
+        .starting_at(min_db))
+    }
+
+    // Builds a fader from the parts of its textual or serialized form.
+    pub(crate) fn try_from_parts(
+        min_db: f64,
+        max_db: f64,
+        steps: &[(f64, f64)],
+        curves: &[Curve],
+        unit: DecibelUnit,
+    ) -> Result<FaderScale<N>, ScaleError> {
+        check_ascending_range(floor_db(min_db), max_db)?;
+        let delegate = if curves.is_empty() {
+            BrokenScale::try_new(floor_db(min_db), max_db, steps)?
+        } else {
+            BrokenScale::try_with_curves(floor_db(min_db), max_db, steps, curves)?
+        };
+        Ok(DecibelScale::with_delegate(delegate, unit).starting_at(min_db))
+    }
+
+    /// The common large-format console law: +10 dB at the top, 0 dB at 75% of the travel, -inf at the bottom.
+    pub fn console_fader() -> FaderScale<N> {
+        FaderScale::fader(
+            -80.0,
+            10.0,
+            &[
+                (-60.0, 0.05),
+                (-40.0, 0.15),
+                (-30.0, 0.25),
+                (-20.0, 0.375),
+                (-10.0, 0.5),
+                (0.0, 0.75),
+                (5.0, 0.875),
+            ],
+        )
+    }
+
+    /// A law common on compact mixers: +6 dB at the top, 0 dB at 80% of the travel, -inf at the bottom.
+    pub fn compact_fader() -> FaderScale<N> {
+        FaderScale::fader(
+            -70.0,
+            6.0,
+            &[
+                (-50.0, 0.05),
+                (-30.0, 0.2),
+                (-20.0, 0.35),
+                (-10.0, 0.55),
+                (0.0, 0.8),
+            ],
+        )
+    }
+}
+
+impl<N, D> DecibelScale<N, D>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    D: Scale<f64>,
+{
+    /// Creates a decibel scale that uses the provided scale to distribute decibels above the point of silence.
+    pub fn with_delegate(db_delegate: D, unit: DecibelUnit) -> DecibelScale<N, D> {
+        DecibelScale {
+            max: N::from_float(unit.from_db(db_delegate.max())),
+            unit,
+            db_delegate,
+            from_silence: false,
+        }
+    }
+
+    // Marks the decibel range as starting at negative infinity if `min_db` is, the delegate has to start at the
+    // silence floor in that case.
+    pub(crate) fn starting_at(mut self, min_db: f64) -> DecibelScale<N, D> {
+        self.from_silence = min_db == f64::NEG_INFINITY;
+        self
+    }
+
+    /// The lower end of the decibel range, negative infinity if the range starts at the point of silence.
+    pub fn min_db(&self) -> f64 {
+        if self.from_silence {
+            f64::NEG_INFINITY
+        } else {
+            self.db_delegate.min()
+        }
+    }
+
+    pub fn max_db(&self) -> f64 {
+        self.db_delegate.max()
+    }
+
+    pub fn unit(&self) -> DecibelUnit {
+        self.unit
+    }
+
+    /// The scale that distributes decibels above the point of silence.
+    pub fn db_delegate(&self) -> &D {
+        &self.db_delegate
+    }
+}
+
+impl<N, D> Scale<N> for DecibelScale<N, D>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    D: Scale<f64>,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let db = self.unit.to_db(absolute.to_float());
+        if db == f64::NEG_INFINITY {
+            0.0
+        } else {
+            self.db_delegate.to_relative(db).max(0.0)
+        }
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        if relative <= 0.0 {
+            N::from_float(0.0)
+        } else {
+            let db = self.db_delegate.to_absolute(relative);
+            N::from_float(self.unit.from_db(db))
+        }
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        N::from_float(0.0)
+    }
+
+    /// The ticks of the decibel range, where the tick at the bottom marks the point of silence.
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        self.db_delegate
+            .ticks(count)
+            .into_iter()
+            .map(|tick| Tick {
+                value: if tick.relative <= 0.0 {
+                    N::from_float(0.0)
+                } else {
+                    N::from_float(self.unit.from_db(tick.value))
+                },
+                relative: tick.relative.max(0.0),
+                major: tick.major,
+            })
+            .collect()
+    }
+}
+
+// Replaces a lower bound of negative infinity with the silence floor.
+fn floor_db(min_db: f64) -> f64 {
+    if min_db == f64::NEG_INFINITY {
+        SILENCE_FLOOR_DB
+    } else {
+        min_db
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_db_conversion() {
+        assert_approx_eq!(DecibelUnit::Amplitude.to_db(1.0), 0.0);
+        assert_approx_eq!(DecibelUnit::Amplitude.to_db(10.0), 20.0);
+        assert_approx_eq!(DecibelUnit::Power.to_db(10.0), 10.0);
+        assert_eq!(DecibelUnit::Amplitude.to_db(0.0), f64::NEG_INFINITY);
+        assert_eq!(DecibelUnit::Amplitude.to_db(-1.0), f64::NEG_INFINITY);
+
+        assert_approx_eq!(DecibelUnit::Amplitude.from_db(-20.0), 0.1);
+        assert_approx_eq!(DecibelUnit::Power.from_db(-20.0), 0.01);
+        assert_eq!(DecibelUnit::Amplitude.from_db(f64::NEG_INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_decibel_scale() {
+        let scale: DecibelScale<f64> = DecibelScale::new(-60.0, 0.0);
+
+        assert_approx_eq!(scale.to_absolute(1.0), 1.0);
+        assert_approx_eq!(scale.to_absolute(2.0 / 3.0), 0.1);
+        assert_approx_eq!(scale.to_absolute(1.0 / 3.0), 0.01);
+
+        assert_approx_eq!(scale.to_relative(1.0), 1.0);
+        assert_approx_eq!(scale.to_relative(0.1), 2.0 / 3.0);
+        assert_approx_eq!(scale.to_relative(0.01), 1.0 / 3.0);
+        assert_approx_eq!(scale.to_relative(0.001), 0.0);
+    }
+
+    #[test]
+    fn test_decibel_silence() {
+        let scale: DecibelScale<f64> = DecibelScale::new(-60.0, 12.0);
+
+        assert_eq!(scale.to_absolute(0.0), 0.0);
+        assert_eq!(scale.to_absolute(-1.0), 0.0);
+        assert_eq!(scale.to_relative(0.0), 0.0);
+        assert_eq!(scale.to_relative(-1.0), 0.0);
+        assert_eq!(scale.to_relative(0.0001), 0.0);
+        assert_eq!(scale.min(), 0.0);
+
+        assert!(scale.to_absolute(0.0001) > 0.0);
+    }
+
+    #[test]
+    fn test_decibel_power() {
+        let scale: DecibelScale<f64> = DecibelScale::power(-30.0, 10.0);
+        assert_approx_eq!(scale.max(), 10.0);
+        assert_approx_eq!(scale.to_absolute(0.75), 1.0);
+        assert_approx_eq!(scale.to_relative(0.1), 0.5);
+    }
+
+    #[test]
+    fn test_decibel_converter() {
+        let fader = LinearScale::new(0.0, 100.0);
+        let gain: DecibelScale<f64> = DecibelScale::new(-100.0, 0.0);
+
+        assert_approx_eq!((&fader, &gain).convert(0.0), 0f64);
+        assert_approx_eq!((&fader, &gain).convert(80.0), 0.1f64);
+        assert_approx_eq!((&fader, &gain).convert_back(0.0), 0f64);
+    }
+
+    #[test]
+    fn test_console_fader() {
+        let fader: FaderScale<f64> = FaderScale::console_fader();
+
+        assert_approx_eq!(fader.max(), DecibelUnit::Amplitude.from_db(10.0));
+        assert_eq!(fader.to_absolute(0.0), 0.0);
+        assert_eq!(fader.to_relative(0.0), 0.0);
+
+        assert_approx_eq!(fader.to_absolute(1.0), DecibelUnit::Amplitude.from_db(10.0));
+        assert_approx_eq!(fader.to_absolute(0.75), 1.0);
+        assert_approx_eq!(
+            fader.to_absolute(0.5),
+            DecibelUnit::Amplitude.from_db(-10.0)
+        );
+
+        assert_approx_eq!(fader.to_relative(1.0), 0.75);
+        assert_approx_eq!(
+            fader.to_relative(DecibelUnit::Amplitude.from_db(-30.0)),
+            0.25
+        );
+        assert_approx_eq!(
+            fader.to_relative(DecibelUnit::Amplitude.from_db(-15.0)),
+            0.4375
+        );
+    }
+
+    #[test]
+    fn test_compact_fader() {
+        let fader: FaderScale<f64> = FaderScale::compact_fader();
+
+        assert_approx_eq!(fader.to_absolute(0.8), 1.0);
+        assert_approx_eq!(fader.to_relative(1.0), 0.8);
+        assert_approx_eq!(fader.max_db(), 6.0);
+        assert_approx_eq!(fader.min_db(), -70.0);
+    }
+
+    #[test]
+    fn test_decibel_from_silence() {
+        let scale: DecibelScale<f64> = DecibelScale::try_new(f64::NEG_INFINITY, 0.0).unwrap();
+        assert_eq!(scale.min_db(), f64::NEG_INFINITY);
+        assert_eq!(scale.db_delegate().min(), SILENCE_FLOOR_DB);
+        assert_approx_eq!(
+            scale.to_absolute(0.5),
+            DecibelUnit::Amplitude.from_db(-60.0)
+        );
+        assert_ne!(scale, DecibelScale::new(SILENCE_FLOOR_DB, 0.0));
+
+        let fader: FaderScale<f64> = FaderScale::fader(f64::NEG_INFINITY, 10.0, &[(0.0, 0.75)]);
+        assert_eq!(fader.min_db(), f64::NEG_INFINITY);
+        assert_approx_eq!(fader.to_absolute(0.75), 1.0);
+    }
+
+    #[test]
+    fn test_fader_try_new() {
+        assert!(FaderScale::<f64>::try_fader(-80.0, 10.0, &[(0.0, 0.75)]).is_ok());
+        assert_eq!(
+            FaderScale::<f64>::try_fader(-80.0, 10.0, &[(0.0, 0.75), (-10.0, 0.8)]),
+            Err(ScaleError::NonMonotonicBreakpoints { index: 1 })
+        );
+        assert_eq!(
+            FaderScale::<f64>::try_fader(10.0, -80.0, &[]),
+            Err(ScaleError::DescendingRange)
+        );
+    }
+
+    #[test]
+    fn test_decibel_try_new() {
+        assert!(DecibelScale::<f64>::try_new(-60.0, 12.0).is_ok());
+        assert_eq!(
+            DecibelScale::<f64>::try_new(-60.0, f64::INFINITY),
+            Err(ScaleError::NonFiniteBound)
+        );
+        assert_eq!(
+            DecibelScale::<f64>::try_new(f64::NEG_INFINITY, -130.0),
+            Err(ScaleError::DescendingRange)
+        );
+        assert_eq!(
+            DecibelScale::<f64>::try_power(12.0, -60.0),
+            Err(ScaleError::DescendingRange)
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use super::convert::*;
+use super::linear::*;
+use super::logarithmic::*;
+use super::*;
+
+/// A scale of any kind whose minimum and maximum can change any time. The bounds are re-evaluated for every
+/// calculation and passed to `build`, which creates the actual scale, e.g. `LogarithmicScale::new` or a closure
+/// that creates a [`BrokenScale`](crate::prelude::BrokenScale) with fixed steps. This makes it possible for a
+/// range to follow other parameters, like a filter cutoff that is bounded by the sample rate.
+///
+/// Since the scale is rebuilt on every call, building it should be cheap.
+#[derive(Debug, Clone)]
+pub struct DynamicScale<Min, Max, B> {
+    min: Min,
+    max: Max,
+    build: B,
+}
+
+impl<Min, Max, B> DynamicScale<Min, Max, B> {
+    pub fn new(min: Min, max: Max, build: B) -> DynamicScale<Min, Max, B> {
+        DynamicScale { min, max, build }
+    }
+}
+
+impl<N, Min, Max> DynamicScale<Min, Max, fn(N, N) -> LinearScale<N>>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    Min: Fn() -> N,
+    Max: Fn() -> N,
+{
+    pub fn linear(min: Min, max: Max) -> Self {
+        DynamicScale::new(min, max, LinearScale::new)
+    }
+}
+
+impl<N, Min, Max> DynamicScale<Min, Max, fn(N, N) -> LogarithmicScale<N>>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    Min: Fn() -> N,
+    Max: Fn() -> N,
+{
+    pub fn logarithmic(min: Min, max: Max) -> Self {
+        DynamicScale::new(min, max, LogarithmicScale::new)
+    }
+}
+
+impl<N, S, Min, Max, B> DynamicScale<Min, Max, B>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+    Min: Fn() -> N,
+    Max: Fn() -> N,
+    B: Fn(N, N) -> S,
+{
+    /// Builds the scale from the current bounds.
+    pub fn current(&self) -> S {
+        let min = &self.min;
+        let max = &self.max;
+        let build = &self.build;
+        build(min(), max())
+    }
+}
+
+impl<N, S, Min, Max, B> Scale<N> for DynamicScale<Min, Max, B>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+    Min: Fn() -> N,
+    Max: Fn() -> N,
+    B: Fn(N, N) -> S,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.current().to_relative(absolute)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.current().to_absolute(relative)
+    }
+
+    fn max(&self) -> N {
+        let max = &self.max;
+        max()
+    }
+
+    fn min(&self) -> N {
+        let min = &self.min;
+        min()
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        self.current()
+            .to_relative_delta(absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        self.current()
+            .to_absolute_delta(relative_delta, absolute_pos)
+    }
+
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        self.current().ticks(count)
+    }
+
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        self.current().to_relative_slice(absolute, relative)
+    }
+
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        self.current().to_absolute_slice(relative, absolute)
+    }
+
+    fn to_relative_in_place(&self, values: &mut [N]) {
+        self.current().to_relative_in_place(values)
+    }
+
+    fn to_absolute_in_place(&self, values: &mut [N]) {
+        self.current().to_absolute_in_place(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_dynamic_logarithmic() {
+        let sample_rate = Cell::new(44_100.0);
+        let cutoff = DynamicScale::logarithmic(|| 20_f64, || sample_rate.get() / 2.0);
+
+        assert_approx_eq!(cutoff.max(), 22_050.0);
+        assert_approx_eq!(cutoff.to_absolute(1.0), 22_050.0);
+
+        sample_rate.set(96_000.0);
+
+        assert_approx_eq!(cutoff.max(), 48_000.0);
+        assert_approx_eq!(cutoff.to_absolute(1.0), 48_000.0);
+        assert_approx_eq!(cutoff.to_relative(20.0), 0.0);
+    }
+
+    #[test]
+    fn test_dynamic_power_and_broken() {
+        let max = Cell::new(100_f64);
+
+        let power = DynamicScale::new(
+            || 0.0,
+            || max.get(),
+            |min, max| PowerScale::new(min, max, 2.0),
+        );
+        let steps = [(50.0, 0.75)];
+        let broken = DynamicScale::new(
+            || 0.0,
+            || max.get(),
+            |min, max| BrokenScale::new(min, max, &steps),
+        );
+
+        assert_approx_eq!(power.to_absolute(0.5), 25.0);
+        assert_approx_eq!(broken.to_absolute(0.75), 50.0);
+
+        max.set(200.0);
+
+        assert_approx_eq!(power.to_absolute(0.5), 50.0);
+        assert_approx_eq!(broken.to_absolute(0.75), 50.0);
+        assert_approx_eq!(broken.to_absolute(0.875), 125.0);
+    }
+
+    #[test]
+    fn test_dynamic_converter() {
+        let max = Cell::new(10.0);
+        let knob = LinearScale::new(0.0, 1.0);
+        let param = DynamicScale::linear(|| 0.0, || max.get());
+
+        assert_approx_eq!((&knob, &param).convert(0.5), 5f64);
+        max.set(20.0);
+        assert_approx_eq!((&knob, &param).convert(0.5), 10f64);
+    }
+}
@@ -0,0 +1,283 @@
+use std::error::Error;
+use std::fmt;
+
+/// The reasons why a scale could not be constructed from a given configuration or a value could not be converted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScaleError {
+    /// The minimum and maximum of the scale are identical, so there is no range to map onto.
+    EmptyRange,
+    /// One of the bounds is NaN or infinite.
+    NonFiniteBound,
+    /// The minimum of a scale that does not support inversion is greater than its maximum.
+    DescendingRange,
+    /// A logarithmic scale was given a bound that is zero or negative.
+    NonPositiveLogBound,
+    /// A frequency scale was given a bound below 0 Hz.
+    NegativeFrequency,
+    /// The breakpoint at the given index is not strictly greater than its predecessor.
+    NonMonotonicBreakpoints { index: usize },
+    /// The breakpoint at the given index lies outside the range of the scale or outside 0.0..1.0.
+    BreakpointOutOfRange { index: usize },
+    /// A scale that is defined by control points was given fewer than it requires.
+    NotEnoughPoints { required: usize, actual: usize },
+    /// The number of segment curves of a broken scale does not match its number of segments.
+    SegmentCountMismatch { expected: usize, actual: usize },
+    /// The exponent of a power scale is not a finite number greater than zero.
+    InvalidExponent,
+    /// The linear threshold of a symmetric logarithmic scale is not a finite number greater than zero.
+    InvalidThreshold,
+    /// The lower half of a bipolar scale does not end where its upper half starts.
+    CentreMismatch,
+    /// The detent width of a bipolar scale is not within `0.0..1.0`.
+    InvalidDetent,
+    /// The reference of a tuning is not a finite note with a finite frequency greater than zero.
+    InvalidTuning,
+    /// A converted value lies outside the range of the target type or is NaN.
+    NotRepresentable { value: f64 },
+    /// A serialized scale describes a different type of scale than the one that was requested.
+    UnexpectedScaleType {
+        expected: &'static str,
+        actual: &'static str,
+    },
+    /// A textual scale specification could not be parsed, the position is a byte offset into the text.
+    InvalidSyntax {
+        position: usize,
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for ScaleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaleError::EmptyRange => write!(f, "minimum and maximum of the scale are equal"),
+            ScaleError::NonFiniteBound => write!(f, "scale bounds must be finite numbers"),
+            ScaleError::DescendingRange => {
+                write!(f, "minimum of the scale must be less than its maximum")
+            }
+            ScaleError::NonPositiveLogBound => {
+                write!(f, "bounds of a logarithmic scale must be greater than zero")
+            }
+            ScaleError::NegativeFrequency => {
+                write!(f, "bounds of a frequency scale must not be negative")
+            }
+            ScaleError::NonMonotonicBreakpoints { index } => write!(
+                f,
+                "breakpoint {} is not strictly greater than its predecessor",
+                index
+            ),
+            ScaleError::BreakpointOutOfRange { index } => {
+                write!(
+                    f,
+                    "breakpoint {} lies outside the range of the scale",
+                    index
+                )
+            }
+            ScaleError::NotEnoughPoints { required, actual } => write!(
+                f,
+                "at least {} points are required but got {}",
+                required, actual
+            ),
+            ScaleError::SegmentCountMismatch { expected, actual } => {
+                write!(f, "expected {} segment curves but got {}", expected, actual)
+            }
+            ScaleError::InvalidExponent => {
+                write!(f, "exponent must be a finite number greater than zero")
+            }
+            ScaleError::InvalidThreshold => {
+                write!(f, "threshold must be a finite number greater than zero")
+            }
+            ScaleError::CentreMismatch => write!(
+                f,
+                "lower half of the scale must end where the upper half starts"
+            ),
+            ScaleError::InvalidDetent => write!(f, "detent width must be within 0.0..1.0"),
+            ScaleError::InvalidTuning => write!(
+                f,
+                "tuning reference must be a finite note with a finite frequency greater than zero"
+            ),
+            ScaleError::NotRepresentable { value } => {
+                write!(f, "{} cannot be represented by the target type", value)
+            }
+            ScaleError::UnexpectedScaleType { expected, actual } => {
+                write!(
+                    f,
+                    "expected a scale of type {} but got {}",
+                    expected, actual
+                )
+            }
+            ScaleError::InvalidSyntax { position, expected } => {
+                write!(f, "expected {} at position {}", expected, position)
+            }
+        }
+    }
+}
+
+impl Error for ScaleError {}
+
+pub(crate) fn check_range(min: f64, max: f64) -> Result<(), ScaleError> {
+    if !min.is_finite() || !max.is_finite() {
+        Err(ScaleError::NonFiniteBound)
+    } else if min == max {
+        Err(ScaleError::EmptyRange)
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn check_ascending_range(min: f64, max: f64) -> Result<(), ScaleError> {
+    check_range(min, max)?;
+    if min > max {
+        Err(ScaleError::DescendingRange)
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn check_log_range(min: f64, max: f64) -> Result<(), ScaleError> {
+    check_range(min, max)?;
+    if min <= 0.0 || max <= 0.0 {
+        Err(ScaleError::NonPositiveLogBound)
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn check_frequency_range(min: f64, max: f64) -> Result<(), ScaleError> {
+    check_range(min, max)?;
+    if min < 0.0 || max < 0.0 {
+        Err(ScaleError::NegativeFrequency)
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn check_breakpoints(points: &[(f64, f64)]) -> Result<(), ScaleError> {
+    let mut previous: Option<(f64, f64)> = None;
+
+    for (index, (x, y)) in points.iter().enumerate() {
+        if !(0.0..=1.0).contains(x) || !(0.0..=1.0).contains(y) {
+            return Err(ScaleError::BreakpointOutOfRange { index });
+        }
+
+        if let Some((px, py)) = previous {
+            if x <= &px || y <= &py {
+                return Err(ScaleError::NonMonotonicBreakpoints { index });
+            }
+        }
+
+        previous = Some((*x, *y));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_points(points: &[(f64, f64)], required: usize) -> Result<(), ScaleError> {
+    if points.len() < required {
+        return Err(ScaleError::NotEnoughPoints {
+            required,
+            actual: points.len(),
+        });
+    }
+
+    let mut previous: Option<(f64, f64)> = None;
+
+    for (index, (x, y)) in points.iter().enumerate() {
+        if !x.is_finite() || !y.is_finite() {
+            return Err(ScaleError::NonFiniteBound);
+        }
+
+        if let Some((px, py)) = previous {
+            if x <= &px || y <= &py {
+                return Err(ScaleError::NonMonotonicBreakpoints { index });
+            }
+        }
+
+        previous = Some((*x, *y));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_exponent(exponent: f64) -> Result<(), ScaleError> {
+    if exponent.is_finite() && exponent > 0.0 {
+        Ok(())
+    } else {
+        Err(ScaleError::InvalidExponent)
+    }
+}
+
+pub(crate) fn check_threshold(threshold: f64) -> Result<(), ScaleError> {
+    if threshold.is_finite() && threshold > 0.0 {
+        Ok(())
+    } else {
+        Err(ScaleError::InvalidThreshold)
+    }
+}
+
+pub(crate) fn check_tuning(reference_note: f64, reference_hz: f64) -> Result<(), ScaleError> {
+    if reference_note.is_finite() && reference_hz.is_finite() && reference_hz > 0.0 {
+        Ok(())
+    } else {
+        Err(ScaleError::InvalidTuning)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_check_range() {
+        assert_eq!(check_range(0.0, 1.0), Ok(()));
+        assert_eq!(check_range(1.0, 0.0), Ok(()));
+        assert_eq!(check_range(1.0, 1.0), Err(ScaleError::EmptyRange));
+        assert_eq!(check_range(f64::NAN, 1.0), Err(ScaleError::NonFiniteBound));
+        assert_eq!(
+            check_range(0.0, f64::INFINITY),
+            Err(ScaleError::NonFiniteBound)
+        );
+    }
+
+    #[test]
+    fn test_check_breakpoints() {
+        assert_eq!(check_breakpoints(&[]), Ok(()));
+        assert_eq!(check_breakpoints(&[(0.25, 0.5), (0.5, 0.75)]), Ok(()));
+        assert_eq!(
+            check_breakpoints(&[(0.5, 0.5), (0.25, 0.75)]),
+            Err(ScaleError::NonMonotonicBreakpoints { index: 1 })
+        );
+        assert_eq!(
+            check_breakpoints(&[(0.25, 0.5), (0.5, 0.5)]),
+            Err(ScaleError::NonMonotonicBreakpoints { index: 1 })
+        );
+        assert_eq!(
+            check_breakpoints(&[(1.5, 0.5)]),
+            Err(ScaleError::BreakpointOutOfRange { index: 0 })
+        );
+        assert_eq!(
+            check_breakpoints(&[(0.5, -0.5)]),
+            Err(ScaleError::BreakpointOutOfRange { index: 0 })
+        );
+    }
+
+    #[test]
+    fn test_check_points() {
+        assert_eq!(check_points(&[(-10.0, 0.0), (10.0, 2.0)], 2), Ok(()));
+        assert_eq!(
+            check_points(&[(-10.0, 0.0)], 2),
+            Err(ScaleError::NotEnoughPoints {
+                required: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            check_points(&[(-10.0, 0.0), (-10.0, 1.0)], 2),
+            Err(ScaleError::NonMonotonicBreakpoints { index: 1 })
+        );
+        assert_eq!(
+            check_points(&[(-10.0, 0.0), (f64::NAN, 1.0)], 2),
+            Err(ScaleError::NonFiniteBound)
+        );
+    }
+}
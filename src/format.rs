@@ -0,0 +1,442 @@
+use super::decibel::*;
+use super::error::*;
+use super::pitch::*;
+use super::ticks::*;
+
+const SI_PREFIXES: [(&str, i32); 9] = [
+    ("p", -12),
+    ("n", -9),
+    ("µ", -6),
+    ("m", -3),
+    ("", 0),
+    ("k", 3),
+    ("M", 6),
+    ("G", 9),
+    ("T", 12),
+];
+
+const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// How the values of a scale are rendered as labels and parsed back from text typed by a user,
+/// see [`Scale::format_value`](crate::Scale::format_value) and [`Scale::parse_value`](crate::Scale::parse_value).
+/// Units are optional when parsing and are matched case-insensitively, SI prefixes are not.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueFormat {
+    /// A plain number with a fixed number of decimals, followed by an optional unit, e.g. `12.5 ms`.
+    Number { unit: String, decimals: usize },
+    /// A number of significant digits with an SI prefix, e.g. `1.2 kHz` or `250 µs`.
+    /// Parsing also accepts `u` for micro.
+    Si { unit: String, digits: usize },
+    /// A linear gain or power ratio in decibels with a fixed number of decimals, e.g. `-6.0 dB`. Silence is `-inf dB`.
+    Decibel { unit: DecibelUnit, decimals: usize },
+    /// The relative position of a value on the scale as a percentage, e.g. `50%`.
+    Percent { decimals: usize },
+    /// The name of the nearest equal-tempered note and the deviation from it in cents, e.g. `A4` or `C#3 +12 ct`,
+    /// for values in the given pitch unit. Parsing also accepts plain values in that unit.
+    Note { unit: PitchUnit, tuning: Tuning },
+}
+
+pub(crate) enum ParsedValue {
+    Absolute(f64),
+    Relative(f64),
+}
+
+impl ValueFormat {
+    pub(crate) fn format(&self, absolute: f64, relative: f64) -> String {
+        match self {
+            ValueFormat::Number { unit, decimals } => {
+                with_unit(format!("{:.*}", decimals, absolute), "", unit)
+            }
+            ValueFormat::Si { unit, digits } => format_si(absolute, unit, *digits),
+            ValueFormat::Decibel { unit, decimals } => {
+                let db = unit.to_db(absolute);
+                if db == f64::NEG_INFINITY {
+                    "-inf dB".to_owned()
+                } else {
+                    format!("{:.*} dB", decimals, db)
+                }
+            }
+            ValueFormat::Percent { decimals } => format!("{:.*}%", decimals, relative * 100.0),
+            ValueFormat::Note { unit, tuning } => format_note(unit.to_note(absolute, tuning)),
+        }
+    }
+
+    pub(crate) fn parse(&self, text: &str) -> Result<ParsedValue, ScaleError> {
+        if let ValueFormat::Note { unit, tuning } = self {
+            let start = text.len() - text.trim_start().len();
+            if text[start..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+                let note = parse_note(text, start)?;
+                return Ok(ParsedValue::Absolute(unit.in_unit(note, tuning)));
+            }
+        }
+
+        let (value, end) = parse_number(text, 0)?;
+        let suffix = text[end..].trim();
+        let suffix_position = text.len() - text[end..].trim_start().len();
+        let invalid_unit = Err(ScaleError::InvalidSyntax {
+            position: suffix_position,
+            expected: "a matching unit",
+        });
+
+        match self {
+            ValueFormat::Number { unit, .. } => {
+                if suffix.is_empty() || suffix.eq_ignore_ascii_case(unit) {
+                    Ok(ParsedValue::Absolute(value))
+                } else {
+                    invalid_unit
+                }
+            }
+            ValueFormat::Si { unit, .. } => match si_exponent(suffix, unit) {
+                Some(exponent) => Ok(ParsedValue::Absolute(pow10(value, exponent))),
+                None => invalid_unit,
+            },
+            ValueFormat::Decibel { unit, .. } => {
+                if suffix.is_empty() || suffix.eq_ignore_ascii_case("dB") {
+                    Ok(ParsedValue::Absolute(unit.from_db(value)))
+                } else {
+                    invalid_unit
+                }
+            }
+            ValueFormat::Percent { .. } => {
+                if suffix.is_empty() || suffix == "%" {
+                    Ok(ParsedValue::Relative(value / 100.0))
+                } else {
+                    invalid_unit
+                }
+            }
+            ValueFormat::Note { unit, .. } => {
+                let unit_name = match unit {
+                    PitchUnit::Hz => "Hz",
+                    PitchUnit::MidiNote => "",
+                    PitchUnit::Cents => "ct",
+                };
+                if suffix.is_empty() || suffix.eq_ignore_ascii_case(unit_name) {
+                    Ok(ParsedValue::Absolute(value))
+                } else {
+                    invalid_unit
+                }
+            }
+        }
+    }
+}
+
+fn with_unit(number: String, prefix: &str, unit: &str) -> String {
+    if prefix.is_empty() && unit.is_empty() {
+        number
+    } else {
+        format!("{} {}{}", number, prefix, unit)
+    }
+}
+
+fn format_si(value: f64, unit: &str, digits: usize) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return with_unit(value.to_string(), "", unit);
+    }
+
+    // round to significant digits first, so that e.g. 999.96 becomes 1 k rather than 1000
+    let digits = digits.max(1) as i32;
+    let magnitude = value.abs().log10().floor() as i32;
+    let rounded = pow10(
+        pow10(value, digits - 1 - magnitude).round(),
+        magnitude + 1 - digits,
+    );
+    let magnitude = rounded.abs().log10().floor() as i32;
+
+    let (prefix, exponent) = SI_PREFIXES
+        .iter()
+        .rev()
+        .find(|(_, exponent)| *exponent <= magnitude)
+        .unwrap_or(&SI_PREFIXES[0]);
+    let mantissa = pow10(rounded, -exponent);
+    let decimals = (digits - 1 - (magnitude - exponent)).max(0) as usize;
+
+    let mut number = format!("{:.*}", decimals, mantissa);
+    if number.contains('.') {
+        number = number
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_owned();
+    }
+    with_unit(number, prefix, unit)
+}
+
+fn si_exponent(suffix: &str, unit: &str) -> Option<i32> {
+    let prefix = if suffix.len() >= unit.len()
+        && suffix.is_char_boundary(suffix.len() - unit.len())
+        && suffix[suffix.len() - unit.len()..].eq_ignore_ascii_case(unit)
+    {
+        &suffix[..suffix.len() - unit.len()]
+    } else {
+        suffix
+    };
+    let prefix = if prefix == "u" { "µ" } else { prefix };
+    SI_PREFIXES
+        .iter()
+        .find(|(name, _)| *name == prefix)
+        .map(|(_, exponent)| *exponent)
+}
+
+fn format_note(note: f64) -> String {
+    if !note.is_finite() {
+        return note.to_string();
+    }
+    let nearest = note.round();
+    let cents = ((note - nearest) * 100.0).round() as i64;
+    let index = nearest as i64;
+    let name = NOTE_NAMES[index.rem_euclid(12) as usize];
+    let octave = index.div_euclid(12) - 1;
+    if cents == 0 {
+        format!("{}{}", name, octave)
+    } else {
+        format!("{}{} {:+} ct", name, octave, cents)
+    }
+}
+
+fn parse_note(text: &str, start: usize) -> Result<f64, ScaleError> {
+    let error = |position, expected| Err(ScaleError::InvalidSyntax { position, expected });
+
+    let semitone = match text[start..].chars().next().map(|c| c.to_ascii_uppercase()) {
+        Some('C') => 0,
+        Some('D') => 2,
+        Some('E') => 4,
+        Some('F') => 5,
+        Some('G') => 7,
+        Some('A') => 9,
+        Some('B') => 11,
+        _ => return error(start, "a note name"),
+    };
+    let mut pos = start + 1;
+    let semitone = match text[pos..].chars().next() {
+        Some('#') => {
+            pos += 1;
+            semitone + 1
+        }
+        Some('b') => {
+            pos += 1;
+            semitone - 1
+        }
+        _ => semitone,
+    };
+
+    let octave_len = text[pos..]
+        .char_indices()
+        .find(|(i, c)| !(c.is_ascii_digit() || (*i == 0 && *c == '-')))
+        .map_or(text.len() - pos, |(i, _)| i);
+    let note = text[pos..pos + octave_len]
+        .parse::<i64>()
+        .ok()
+        .and_then(|octave| {
+            octave
+                .checked_add(1)?
+                .checked_mul(12)?
+                .checked_add(semitone)
+        });
+    let mut note = match note {
+        Some(note) => note as f64,
+        None => return error(pos, "an octave"),
+    };
+    pos += octave_len;
+
+    if !text[pos..].trim().is_empty() {
+        let (cents, end) = parse_number(text, pos)?;
+        let suffix = text[end..].trim();
+        if !(suffix.is_empty()
+            || suffix.eq_ignore_ascii_case("ct")
+            || suffix.eq_ignore_ascii_case("cents"))
+        {
+            return error(text.len() - text[end..].trim_start().len(), "cents");
+        }
+        note += cents / 100.0;
+    }
+    Ok(note)
+}
+
+/// Parses a number starting at `start`, skipping leading whitespace. Returns the number and the position after it.
+fn parse_number(text: &str, start: usize) -> Result<(f64, usize), ScaleError> {
+    let begin = text.len() - text[start..].trim_start().len();
+    let rest = &text[begin..];
+    let unsigned = rest.strip_prefix(['+', '-']).unwrap_or(rest);
+    let sign_len = rest.len() - unsigned.len();
+
+    let len = if unsigned.starts_with("inf") {
+        3
+    } else {
+        let mantissa = unsigned
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(unsigned.len());
+        let exponent = unsigned[mantissa..]
+            .strip_prefix(['e', 'E'])
+            .map(|exponent| {
+                let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                let count = digits
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(digits.len());
+                if count == 0 {
+                    0
+                } else {
+                    1 + exponent.len() - digits.len() + count
+                }
+            })
+            .unwrap_or(0);
+        mantissa + exponent
+    };
+
+    match rest[..sign_len + len].parse() {
+        Ok(value) => Ok((value, begin + sign_len + len)),
+        Err(_) => Err(ScaleError::InvalidSyntax {
+            position: begin,
+            expected: "a number",
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    fn hz() -> ValueFormat {
+        ValueFormat::Si {
+            unit: "Hz".to_owned(),
+            digits: 3,
+        }
+    }
+
+    #[test]
+    fn test_si() {
+        let scale: LogarithmicScale<f64> = LogarithmicScale::new(20.0, 20_000.0);
+
+        assert_eq!(scale.format_value(1200.0, &hz()), "1.2 kHz");
+        assert_eq!(scale.format_value(1234.5, &hz()), "1.23 kHz");
+        assert_eq!(scale.format_value(20.0, &hz()), "20 Hz");
+        assert_eq!(scale.format_value(999.96, &hz()), "1 kHz");
+        assert_eq!(scale.format_value(0.00025, &hz()), "250 µHz");
+        assert_eq!(scale.format_value(0.0, &hz()), "0 Hz");
+
+        assert_approx_eq!(scale.parse_value("1.2 kHz", &hz()).unwrap(), 1200.0);
+        assert_approx_eq!(scale.parse_value("1.2khz", &hz()).unwrap(), 1200.0);
+        assert_approx_eq!(scale.parse_value(" 1.2k ", &hz()).unwrap(), 1200.0);
+        assert_approx_eq!(scale.parse_value("440", &hz()).unwrap(), 440.0);
+        assert_approx_eq!(scale.parse_value("250 uHz", &hz()).unwrap(), 0.00025);
+        assert_eq!(
+            scale.parse_value("1.2 kV", &hz()),
+            Err(ScaleError::InvalidSyntax {
+                position: 4,
+                expected: "a matching unit"
+            })
+        );
+        assert_eq!(
+            scale.parse_value("loud", &hz()),
+            Err(ScaleError::InvalidSyntax {
+                position: 0,
+                expected: "a number"
+            })
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        let scale: LinearScale<f64> = LinearScale::new(0.0, 100.0);
+        let format = ValueFormat::Number {
+            unit: "ms".to_owned(),
+            decimals: 1,
+        };
+
+        assert_eq!(scale.format_value(12.345, &format), "12.3 ms");
+        assert_approx_eq!(scale.parse_value("12.5 ms", &format).unwrap(), 12.5);
+        assert_approx_eq!(scale.parse_value("-1e1", &format).unwrap(), -10.0);
+    }
+
+    #[test]
+    fn test_decibel() {
+        let fader: FaderScale<f64> = FaderScale::console_fader();
+        let format = ValueFormat::Decibel {
+            unit: DecibelUnit::Amplitude,
+            decimals: 1,
+        };
+
+        assert_eq!(fader.format_relative(0.75, &format), "0.0 dB");
+        assert_eq!(fader.format_relative(0.0, &format), "-inf dB");
+        assert_eq!(fader.format_value(0.5, &format), "-6.0 dB");
+
+        assert_approx_eq!(fader.parse_relative("0 dB", &format).unwrap(), 0.75);
+        assert_approx_eq!(fader.parse_value("-20", &format).unwrap(), 0.1);
+        assert_eq!(fader.parse_value("-inf dB", &format).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_percent() {
+        let scale: LogarithmicScale<f64> = LogarithmicScale::new(1.0, 100.0);
+        let format = ValueFormat::Percent { decimals: 0 };
+
+        assert_eq!(scale.format_value(10.0, &format), "50%");
+        assert_approx_eq!(scale.parse_value("50 %", &format).unwrap(), 10.0);
+        assert_approx_eq!(scale.parse_relative("25", &format).unwrap(), 0.25);
+    }
+
+    #[test]
+    fn test_note() {
+        let scale: PitchScale<f64> = PitchScale::hz(20.0, 20_000.0);
+        let format = ValueFormat::Note {
+            unit: PitchUnit::Hz,
+            tuning: Tuning::default(),
+        };
+
+        assert_eq!(scale.format_value(440.0, &format), "A4");
+        assert_eq!(scale.format_value(261.6256, &format), "C4");
+        assert_eq!(scale.format_value(445.0, &format), "A4 +20 ct");
+        assert_eq!(scale.format_value(27.5, &format), "A0");
+
+        assert_approx_eq!(scale.parse_value("A4", &format).unwrap(), 440.0);
+        assert_approx_eq!(scale.parse_value("a3", &format).unwrap(), 220.0);
+        assert_approx_eq!(scale.parse_value("Bb3", &format).unwrap(), 233.0818808);
+        assert_approx_eq!(
+            scale.parse_value("A4 +20 ct", &format).unwrap(),
+            445.1125537
+        );
+        assert_approx_eq!(scale.parse_value("C#-1", &format).unwrap(), 8.6619572);
+        assert_approx_eq!(scale.parse_value("1000 Hz", &format).unwrap(), 1000.0);
+        assert!(scale.parse_value("H4", &format).is_err());
+        assert!(scale.parse_value("A", &format).is_err());
+        assert_eq!(
+            scale.parse_value("C922337203685477580", &format),
+            Err(ScaleError::InvalidSyntax {
+                position: 1,
+                expected: "an octave"
+            })
+        );
+
+        let midi = ValueFormat::Note {
+            unit: PitchUnit::MidiNote,
+            tuning: Tuning::default(),
+        };
+        let notes: LinearScale<u8> = LinearScale::new(0, 127);
+        assert_eq!(notes.format_value(60, &midi), "C4");
+        assert_eq!(notes.parse_value("G9", &midi).unwrap(), 127);
+    }
+
+    #[test]
+    fn test_parse_integer_value() {
+        let format = ValueFormat::Number {
+            unit: String::new(),
+            decimals: 0,
+        };
+        let scale: LinearScale<i32> = LinearScale::new(0, 100);
+        assert_eq!(scale.parse_value("12.7", &format), Ok(13));
+        assert_eq!(scale.parse_value("-12.7", &format), Ok(-13));
+        assert_eq!(scale.parse_value("12.5", &format), Ok(12));
+        assert_eq!(
+            scale.parse_value("3e9", &format),
+            Err(ScaleError::NotRepresentable { value: 3e9 })
+        );
+
+        let unsigned: LinearScale<u8> = LinearScale::new(0, 100);
+        assert_eq!(
+            unsigned.parse_value("-1", &format),
+            Err(ScaleError::NotRepresentable { value: -1.0 })
+        );
+    }
+}
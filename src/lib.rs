@@ -1,16 +1,42 @@
 pub mod prelude;
 
+mod any;
+mod approx;
+mod bipolar;
 mod broken;
+mod circular;
 mod convert;
 mod converter;
+mod decibel;
+mod dynamic;
+mod error;
+mod format;
 mod linear;
 mod logarithmic;
+mod observable;
+mod pitch;
+mod power;
+mod psychoacoustic;
+mod rounded;
+#[cfg(feature = "serde")]
+mod serialization;
+mod shared;
+mod smoothing;
+mod spec;
+mod spline;
+mod stepped;
+mod symlog;
+mod table;
+mod ticks;
 
 use convert::*;
+use error::*;
+use format::*;
 use std::cell::RefCell;
 use std::ops::*;
 use std::rc::Rc;
 use std::sync::Arc;
+use ticks::*;
 
 /// A scale is a mapping of an arbitrary, not necessarily linear, continuous and monotonically
 /// increasing range of numbers to a relative value between 0.0 and 1.0.
@@ -39,20 +65,11 @@ where
     }
 
     fn to_clamped_absolute(&self, relative: f64) -> N {
-        let relative: f64 = relative.to_float();
-        let relative = if relative > 1.0 {
-            1.0
-        } else if relative < 0.0 {
-            0.0
-        } else {
-            relative
-        };
-
-        self.to_absolute(relative)
+        self.to_absolute(relative.clamp(0.0, 1.0))
     }
 
     fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
-        let absolute_pos = self.to_absolute(relative_pos.clone());
+        let absolute_pos = self.to_absolute(relative_pos);
         let rel_pos_out = self.to_relative(absolute_pos + absolute_delta);
         rel_pos_out - relative_pos
     }
@@ -62,12 +79,81 @@ where
         let abs_pos_out = self.to_absolute(relative_pos + relative_delta);
         abs_pos_out - absolute_pos
     }
+
+    /// Converts a whole buffer of absolute values to relative values. Panics if the slices differ in length.
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        assert_eq!(absolute.len(), relative.len(), "slice lengths differ");
+        for (absolute, relative) in absolute.iter().zip(relative) {
+            *relative = self.to_relative(absolute.clone());
+        }
+    }
+
+    /// Converts a whole buffer of relative values to absolute values. Panics if the slices differ in length.
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        assert_eq!(relative.len(), absolute.len(), "slice lengths differ");
+        for (relative, absolute) in relative.iter().zip(absolute) {
+            *absolute = self.to_absolute(*relative);
+        }
+    }
+
+    /// Replaces every absolute value in a buffer by its relative value, which is mostly useful for floating point buffers.
+    fn to_relative_in_place(&self, values: &mut [N]) {
+        for value in values.iter_mut() {
+            *value = N::from_float(self.to_relative(value.clone()));
+        }
+    }
+
+    /// Replaces every relative value in a buffer by its absolute value, which is mostly useful for floating point buffers.
+    fn to_absolute_in_place(&self, values: &mut [N]) {
+        for value in values.iter_mut() {
+            *value = self.to_absolute(value.clone().to_float());
+        }
+    }
+
+    /// Tick marks for an axis of this scale with about `count` major ticks, ordered by their relative position.
+    /// By default they are spaced in 1-2-5 steps over the absolute range.
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        let values = nice_values(self.min().to_float(), self.max().to_float(), count);
+        to_ticks(self, values)
+    }
+
+    /// Renders an absolute value of this scale as a human readable label.
+    fn format_value(&self, absolute: N, format: &ValueFormat) -> String {
+        let relative = self.to_relative(absolute.clone());
+        format.format(absolute.to_float(), relative)
+    }
+
+    /// Parses a label typed by a user back into an absolute value. The result is not clamped to the range of the scale,
+    /// but rounded to the nearest value of `N` and rejected if `N` can't represent it.
+    fn parse_value(&self, text: &str, format: &ValueFormat) -> Result<N, ScaleError>
+    where
+        N: RoundFromFloat,
+    {
+        match format.parse(text)? {
+            ParsedValue::Absolute(value) => N::nearest_from_float(value),
+            ParsedValue::Relative(relative) => Ok(self.to_absolute(relative)),
+        }
+    }
+
+    /// Renders the absolute value at a relative position as a human readable label.
+    fn format_relative(&self, relative: f64, format: &ValueFormat) -> String {
+        self.format_value(self.to_absolute(relative), format)
+    }
+
+    /// Parses a label typed by a user into the relative position of its value.
+    fn parse_relative(&self, text: &str, format: &ValueFormat) -> Result<f64, ScaleError>
+    where
+        N: RoundFromFloat,
+    {
+        self.parse_value(text, format)
+            .map(|absolute| self.to_relative(absolute))
+    }
 }
 
 impl<N, SN> Scale<N> for &SN
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
-    SN: Scale<N>,
+    SN: Scale<N> + ?Sized,
 {
     fn to_relative(&self, absolute: N) -> f64 {
         SN::to_relative(self, absolute)
@@ -84,12 +170,40 @@ where
     fn min(&self) -> N {
         SN::min(self)
     }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        SN::to_relative_delta(self, absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        SN::to_absolute_delta(self, relative_delta, absolute_pos)
+    }
+
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        SN::ticks(self, count)
+    }
+
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        SN::to_relative_slice(self, absolute, relative)
+    }
+
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        SN::to_absolute_slice(self, relative, absolute)
+    }
+
+    fn to_relative_in_place(&self, values: &mut [N]) {
+        SN::to_relative_in_place(self, values)
+    }
+
+    fn to_absolute_in_place(&self, values: &mut [N]) {
+        SN::to_absolute_in_place(self, values)
+    }
 }
 
 impl<N, SN> Scale<N> for Box<SN>
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
-    SN: Scale<N>,
+    SN: Scale<N> + ?Sized,
 {
     fn to_relative(&self, absolute: N) -> f64 {
         SN::to_relative(self, absolute)
@@ -106,12 +220,40 @@ where
     fn min(&self) -> N {
         SN::min(self)
     }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        SN::to_relative_delta(self, absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        SN::to_absolute_delta(self, relative_delta, absolute_pos)
+    }
+
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        SN::ticks(self, count)
+    }
+
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        SN::to_relative_slice(self, absolute, relative)
+    }
+
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        SN::to_absolute_slice(self, relative, absolute)
+    }
+
+    fn to_relative_in_place(&self, values: &mut [N]) {
+        SN::to_relative_in_place(self, values)
+    }
+
+    fn to_absolute_in_place(&self, values: &mut [N]) {
+        SN::to_absolute_in_place(self, values)
+    }
 }
 
 impl<N, SN> Scale<N> for Rc<SN>
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
-    SN: Scale<N>,
+    SN: Scale<N> + ?Sized,
 {
     fn to_relative(&self, absolute: N) -> f64 {
         SN::to_relative(self, absolute)
@@ -128,12 +270,40 @@ where
     fn min(&self) -> N {
         SN::min(self)
     }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        SN::to_relative_delta(self, absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        SN::to_absolute_delta(self, relative_delta, absolute_pos)
+    }
+
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        SN::ticks(self, count)
+    }
+
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        SN::to_relative_slice(self, absolute, relative)
+    }
+
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        SN::to_absolute_slice(self, relative, absolute)
+    }
+
+    fn to_relative_in_place(&self, values: &mut [N]) {
+        SN::to_relative_in_place(self, values)
+    }
+
+    fn to_absolute_in_place(&self, values: &mut [N]) {
+        SN::to_absolute_in_place(self, values)
+    }
 }
 
 impl<N, SN> Scale<N> for RefCell<SN>
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
-    SN: Scale<N>,
+    SN: Scale<N> + ?Sized,
 {
     fn to_relative(&self, absolute: N) -> f64 {
         SN::to_relative(self.borrow().deref(), absolute)
@@ -150,12 +320,40 @@ where
     fn min(&self) -> N {
         SN::min(self.borrow().deref())
     }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        SN::to_relative_delta(self.borrow().deref(), absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        SN::to_absolute_delta(self.borrow().deref(), relative_delta, absolute_pos)
+    }
+
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        SN::ticks(self.borrow().deref(), count)
+    }
+
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        SN::to_relative_slice(self.borrow().deref(), absolute, relative)
+    }
+
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        SN::to_absolute_slice(self.borrow().deref(), relative, absolute)
+    }
+
+    fn to_relative_in_place(&self, values: &mut [N]) {
+        SN::to_relative_in_place(self.borrow().deref(), values)
+    }
+
+    fn to_absolute_in_place(&self, values: &mut [N]) {
+        SN::to_absolute_in_place(self.borrow().deref(), values)
+    }
 }
 
 impl<N, SN> Scale<N> for Arc<SN>
 where
     N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
-    SN: Scale<N>,
+    SN: Scale<N> + ?Sized,
 {
     fn to_relative(&self, absolute: N) -> f64 {
         SN::to_relative(self, absolute)
@@ -172,6 +370,34 @@ where
     fn min(&self) -> N {
         SN::min(self)
     }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        SN::to_relative_delta(self, absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        SN::to_absolute_delta(self, relative_delta, absolute_pos)
+    }
+
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        SN::ticks(self, count)
+    }
+
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        SN::to_relative_slice(self, absolute, relative)
+    }
+
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        SN::to_absolute_slice(self, relative, absolute)
+    }
+
+    fn to_relative_in_place(&self, values: &mut [N]) {
+        SN::to_relative_in_place(self, values)
+    }
+
+    fn to_absolute_in_place(&self, values: &mut [N]) {
+        SN::to_absolute_in_place(self, values)
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use super::convert::*;
+use super::error::*;
 use super::*;
 /// A linear scale implementation with a fixed minimum and maximum that can optionally be inverted.
 #[derive(Debug, Clone, PartialEq)]
@@ -41,6 +42,57 @@ where
             inverted: true,
         }
     }
+
+    /// Like [`LinearScale::new`], but rejects non-finite and empty ranges.
+    pub fn try_new(min: N, max: N) -> Result<LinearScale<N>, ScaleError> {
+        check_range(min.clone().to_float(), max.clone().to_float())?;
+        Ok(LinearScale::new(min, max))
+    }
+
+    /// Like [`LinearScale::inverted`], but rejects non-finite and empty ranges.
+    pub fn try_inverted(min: N, max: N) -> Result<LinearScale<N>, ScaleError> {
+        check_range(min.clone().to_float(), max.clone().to_float())?;
+        Ok(LinearScale::inverted(min, max))
+    }
+
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    // The loops below hoist the inversion out of the loop body so they can be vectorised.
+
+    pub(crate) fn relative_in_place_f64(&self, values: &mut [f64]) {
+        let min = self.min_f64;
+        let range = self.full_range;
+        if self.inverted {
+            values
+                .iter_mut()
+                .for_each(|value| *value = 1.0 - (*value - min) / range);
+        } else {
+            values
+                .iter_mut()
+                .for_each(|value| *value = (*value - min) / range);
+        }
+    }
+
+    pub(crate) fn absolute_slice_with<T>(
+        &self,
+        relative: &[f64],
+        absolute: &mut [T],
+        convert: impl Fn(f64) -> T,
+    ) {
+        assert_eq!(relative.len(), absolute.len(), "slice lengths differ");
+        let min = self.min_f64;
+        let range = self.full_range;
+        let pairs = relative.iter().zip(absolute);
+        if self.inverted {
+            pairs.for_each(|(relative, absolute)| {
+                *absolute = convert(min + (1.0 - relative) * range)
+            });
+        } else {
+            pairs.for_each(|(relative, absolute)| *absolute = convert(min + relative * range));
+        }
+    }
 }
 
 impl<N> Scale<N> for LinearScale<N>
@@ -77,6 +129,18 @@ where
     fn min(&self) -> N {
         self.min.clone()
     }
+
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        assert_eq!(absolute.len(), relative.len(), "slice lengths differ");
+        for (absolute, relative) in absolute.iter().zip(relative.iter_mut()) {
+            *relative = absolute.clone().to_float();
+        }
+        self.relative_in_place_f64(relative);
+    }
+
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        self.absolute_slice_with(relative, absolute, N::from_float);
+    }
 }
 
 /// A linear scale implementation where the minimum and maximum can change any time and need to be re-evaluated for every calculation.
@@ -125,7 +189,7 @@ where
         let absolute = absolute.to_float();
 
         let min = self.min().to_float();
-        let max = self.min().to_float();
+        let max = self.max().to_float();
 
         let partial_range = absolute - min;
         let full_range = max - min;
@@ -145,7 +209,7 @@ where
         };
 
         let min = self.min().to_float();
-        let max = self.min().to_float();
+        let max = self.max().to_float();
 
         let full_range = max - min;
         let partial = relative * full_range;
@@ -154,13 +218,13 @@ where
     }
 
     fn max(&self) -> N {
-        let min = &self.min;
-        min()
+        let max = &self.max;
+        max()
     }
 
     fn min(&self) -> N {
-        let max = &self.max;
-        max()
+        let min = &self.min;
+        min()
     }
 }
 
@@ -169,6 +233,7 @@ mod tests {
 
     use crate::prelude::*;
     use assert_approx_eq::*;
+    use std::cell::Cell;
 
     #[test]
     fn test_linear_to_rel_f64() {
@@ -271,4 +336,79 @@ mod tests {
         assert_approx_eq!(scale.to_absolute(0.5), 50.0);
         assert_approx_eq!(scale.to_absolute(0.9), 10.0);
     }
+
+    #[test]
+    fn test_try_new() {
+        assert!(LinearScale::try_new(0.0, 100.0).is_ok());
+        assert!(LinearScale::try_new(100.0, 0.0).is_ok());
+        assert!(LinearScale::try_inverted(0, 100).is_ok());
+        assert_eq!(LinearScale::try_new(5.0, 5.0), Err(ScaleError::EmptyRange));
+        assert_eq!(LinearScale::try_inverted(7, 7), Err(ScaleError::EmptyRange));
+        assert_eq!(
+            LinearScale::try_new(f64::NEG_INFINITY, 0.0),
+            Err(ScaleError::NonFiniteBound)
+        );
+        assert_eq!(
+            LinearScale::try_inverted(0.0, f64::NAN),
+            Err(ScaleError::NonFiniteBound)
+        );
+    }
+
+    #[test]
+    fn test_dynamic_linear() {
+        let max = Cell::new(100.0);
+        let scale = DynamicLinearScale::new(|| 0_f64, || max.get());
+
+        assert_approx_eq!(scale.min(), 0.0);
+        assert_approx_eq!(scale.max(), 100.0);
+        assert_approx_eq!(scale.to_relative(25.0), 0.25);
+        assert_approx_eq!(scale.to_absolute(0.25), 25.0);
+
+        max.set(200.0);
+
+        assert_approx_eq!(scale.max(), 200.0);
+        assert_approx_eq!(scale.to_relative(25.0), 0.125);
+        assert_approx_eq!(scale.to_absolute(0.25), 50.0);
+
+        let inverted = DynamicLinearScale::inverted(|| 0_f64, || max.get());
+        assert_approx_eq!(inverted.to_absolute(0.25), 150.0);
+        assert_approx_eq!(inverted.to_relative(150.0), 0.25);
+    }
+
+    #[test]
+    fn test_slices() {
+        let values: Vec<f64> = (0..1000).map(|i| i as f64 * 0.1 - 20.0).collect();
+        let mut relative = vec![0.0; values.len()];
+        let mut absolute = vec![0.0; values.len()];
+
+        for scale in &[
+            LinearScale::new(-20.0, 80.0),
+            LinearScale::inverted(-20.0, 80.0),
+        ] {
+            scale.to_relative_slice(&values, &mut relative);
+            scale.to_absolute_slice(&relative, &mut absolute);
+            for (i, value) in values.iter().enumerate() {
+                assert_eq!(relative[i], scale.to_relative(*value));
+                assert_eq!(absolute[i], scale.to_absolute(relative[i]));
+            }
+
+            let mut in_place = values.clone();
+            scale.to_relative_in_place(&mut in_place);
+            assert_eq!(in_place, relative);
+            scale.to_absolute_in_place(&mut in_place);
+            assert_eq!(in_place, absolute);
+        }
+
+        let scale: LinearScale<f32> = LinearScale::new(0.0, 10.0);
+        let mut absolute = [0f32; 3];
+        scale.to_absolute_slice(&[0.0, 0.25, 1.0], &mut absolute);
+        assert_eq!(absolute, [0.0, 2.5, 10.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_length_mismatch() {
+        let scale: LinearScale<f64> = LinearScale::new(0.0, 1.0);
+        scale.to_relative_slice(&[0.0, 1.0], &mut [0.0]);
+    }
 }
@@ -0,0 +1,281 @@
+use super::convert::*;
+use super::*;
+use std::fmt;
+
+/// Identifies a subscription to an [`ObservableScale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(usize);
+
+/// Describes how an [`ObservableScale`] changed and how that affected the value tracked by a subscriber.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleChange<N> {
+    /// The minimum or maximum of the scale changed.
+    pub bounds_changed: bool,
+    /// The scale was inverted or un-inverted.
+    pub inversion_changed: bool,
+    /// Neither bounds nor inversion changed but the scale did otherwise, e.g. its breakpoints or curves.
+    pub shape_changed: bool,
+    /// The absolute value tracked by the subscriber.
+    pub value: N,
+    /// The relative position of the tracked value before the change.
+    pub old_relative: f64,
+    /// The relative position of the tracked value after the change.
+    pub new_relative: f64,
+}
+
+type Callback<N> = Box<dyn FnMut(&ScaleChange<N>)>;
+
+struct Observer<N> {
+    id: SubscriptionId,
+    value: N,
+    callback: Callback<N>,
+}
+
+/// Wraps a scale and notifies subscribers whenever it is replaced by a different one, so that e.g. UI widgets bound
+/// through a [`Converter`](crate::prelude::Converter) can redraw themselves. Each subscriber tracks an absolute value
+/// and learns about its relative position before and after the change.
+///
+/// Mutation requires `&mut self`; use a [`RefCell`] to share an observable scale between widgets.
+pub struct ObservableScale<N, S> {
+    scale: S,
+    observers: Vec<Observer<N>>,
+    next_id: usize,
+}
+
+impl<N, S> ObservableScale<N, S>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N> + PartialEq,
+{
+    pub fn new(scale: S) -> ObservableScale<N, S> {
+        ObservableScale {
+            scale,
+            observers: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    pub fn scale(&self) -> &S {
+        &self.scale
+    }
+
+    /// Registers a callback that is invoked with the old and new relative position of `value` whenever the scale changes.
+    pub fn subscribe(
+        &mut self,
+        value: N,
+        callback: impl FnMut(&ScaleChange<N>) + 'static,
+    ) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id);
+        self.next_id += 1;
+        self.observers.push(Observer {
+            id,
+            value,
+            callback: Box::new(callback),
+        });
+        id
+    }
+
+    /// Removes a subscription. Returns `false` if there was no such subscription.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let count = self.observers.len();
+        self.observers.retain(|o| o.id != id);
+        self.observers.len() != count
+    }
+
+    /// Changes the absolute value tracked by a subscription. Returns `false` if there was no such subscription.
+    pub fn track(&mut self, id: SubscriptionId, value: N) -> bool {
+        match self.observers.iter_mut().find(|o| o.id == id) {
+            Some(observer) => {
+                observer.value = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replaces the scale, notifies all subscribers if it differs from the current one and returns the old scale.
+    pub fn replace(&mut self, scale: S) -> S {
+        let old = std::mem::replace(&mut self.scale, scale);
+
+        if old != self.scale {
+            let new = &self.scale;
+            let bounds_changed = old.min() != new.min() || old.max() != new.max();
+            let inversion_changed = is_inverted(&old) != is_inverted(new);
+            let shape_changed = !bounds_changed && !inversion_changed;
+
+            for observer in self.observers.iter_mut() {
+                let change = ScaleChange {
+                    bounds_changed,
+                    inversion_changed,
+                    shape_changed,
+                    value: observer.value.clone(),
+                    old_relative: old.to_relative(observer.value.clone()),
+                    new_relative: new.to_relative(observer.value.clone()),
+                };
+                (observer.callback)(&change);
+            }
+        }
+
+        old
+    }
+
+    /// Replaces the scale by one derived from the current one, see [`ObservableScale::replace`].
+    pub fn update(&mut self, update: impl FnOnce(&S) -> S) -> S {
+        let scale = update(&self.scale);
+        self.replace(scale)
+    }
+}
+
+fn is_inverted<N, S>(scale: &S) -> bool
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    scale.to_relative(scale.min()) > scale.to_relative(scale.max())
+}
+
+impl<N, S> Scale<N> for ObservableScale<N, S>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.scale.to_relative(absolute)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.scale.to_absolute(relative)
+    }
+
+    fn max(&self) -> N {
+        self.scale.max()
+    }
+
+    fn min(&self) -> N {
+        self.scale.min()
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        self.scale.to_relative_delta(absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        self.scale.to_absolute_delta(relative_delta, absolute_pos)
+    }
+
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        self.scale.ticks(count)
+    }
+
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        self.scale.to_relative_slice(absolute, relative)
+    }
+
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        self.scale.to_absolute_slice(relative, absolute)
+    }
+
+    fn to_relative_in_place(&self, values: &mut [N]) {
+        self.scale.to_relative_in_place(values)
+    }
+
+    fn to_absolute_in_place(&self, values: &mut [N]) {
+        self.scale.to_absolute_in_place(values)
+    }
+}
+
+impl<N, S> fmt::Debug for ObservableScale<N, S>
+where
+    S: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ObservableScale")
+            .field("scale", &self.scale)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_bounds_change() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let mut scale = ObservableScale::new(LinearScale::new(0.0f64, 100.0));
+
+        let log = changes.clone();
+        scale.subscribe(50.0, move |change| log.borrow_mut().push(change.clone()));
+
+        scale.replace(LinearScale::new(0.0, 200.0));
+
+        let changes = changes.borrow();
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0].bounds_changed);
+        assert!(!changes[0].inversion_changed);
+        assert!(!changes[0].shape_changed);
+        assert_approx_eq!(changes[0].value, 50.0);
+        assert_approx_eq!(changes[0].old_relative, 0.5);
+        assert_approx_eq!(changes[0].new_relative, 0.25);
+    }
+
+    #[test]
+    fn test_inversion_and_shape_change() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let mut scale = ObservableScale::new(BrokenScale::new(0.0f64, 100.0, &[]));
+
+        let log = changes.clone();
+        let id = scale.subscribe(25.0, move |change| log.borrow_mut().push(change.clone()));
+
+        scale.replace(BrokenScale::new(0.0, 100.0, &[(25.0, 0.5)]));
+        scale.track(id, 50.0);
+        scale.update(|_| BrokenScale::new(0.0, 100.0, &[(25.0, 0.5)]));
+
+        let changes = changes.borrow();
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0].shape_changed);
+        assert!(!changes[0].bounds_changed);
+        assert_approx_eq!(changes[0].old_relative, 0.25);
+        assert_approx_eq!(changes[0].new_relative, 0.5);
+
+        let mut linear = ObservableScale::new(LinearScale::new(0.0f64, 10.0));
+        let inverted = Rc::new(RefCell::new(false));
+        let flag = inverted.clone();
+        linear.subscribe(0.0, move |change| {
+            *flag.borrow_mut() = change.inversion_changed && !change.bounds_changed
+        });
+        linear.replace(LinearScale::inverted(0.0, 10.0));
+        assert!(*inverted.borrow());
+    }
+
+    #[test]
+    fn test_unsubscribe() {
+        let count = Rc::new(RefCell::new(0));
+        let mut scale = ObservableScale::new(LinearScale::new(0.0f64, 1.0));
+
+        let counter = count.clone();
+        let id = scale.subscribe(0.5, move |_| *counter.borrow_mut() += 1);
+
+        scale.replace(LinearScale::new(0.0, 2.0));
+        assert!(scale.unsubscribe(id));
+        assert!(!scale.unsubscribe(id));
+        scale.replace(LinearScale::new(0.0, 3.0));
+
+        assert_eq!(*count.borrow(), 1);
+    }
+
+    #[test]
+    fn test_observable_converter() {
+        let knob = LinearScale::new(0.0, 1.0);
+        let param = RefCell::new(ObservableScale::new(LinearScale::new(0.0, 10.0)));
+
+        assert_approx_eq!((&knob, &param).convert(0.5), 5f64);
+        param.borrow_mut().replace(LinearScale::new(0.0, 20.0));
+        assert_approx_eq!((&knob, &param).convert(0.5), 10f64);
+    }
+}
@@ -0,0 +1,343 @@
+use super::convert::*;
+use super::error::*;
+use super::linear::*;
+use super::*;
+
+/// An equal-tempered tuning, defined by the frequency of a reference note. Defaults to A4 (MIDI note 69) = 440 Hz.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Tuning {
+    reference_note: f64,
+    reference_hz: f64,
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Tuning::new(440.0)
+    }
+}
+
+impl Tuning {
+    /// Creates a tuning where A4 (MIDI note 69) has the given frequency.
+    pub fn new(a4_hz: f64) -> Tuning {
+        Tuning::with_reference(69.0, a4_hz)
+    }
+
+    /// Creates a tuning where the given (possibly fractional) MIDI note has the given frequency.
+    pub fn with_reference(reference_note: f64, reference_hz: f64) -> Tuning {
+        Tuning {
+            reference_note,
+            reference_hz,
+        }
+    }
+
+    /// Like [`Tuning::new`], but rejects frequencies that are not finite or not greater than zero.
+    pub fn try_new(a4_hz: f64) -> Result<Tuning, ScaleError> {
+        Tuning::try_with_reference(69.0, a4_hz)
+    }
+
+    /// Like [`Tuning::with_reference`], but rejects notes that are not finite as well as frequencies that are
+    /// not finite or not greater than zero.
+    pub fn try_with_reference(
+        reference_note: f64,
+        reference_hz: f64,
+    ) -> Result<Tuning, ScaleError> {
+        check_tuning(reference_note, reference_hz)?;
+        Ok(Tuning::with_reference(reference_note, reference_hz))
+    }
+
+    pub fn reference_note(&self) -> f64 {
+        self.reference_note
+    }
+
+    pub fn reference_hz(&self) -> f64 {
+        self.reference_hz
+    }
+
+    /// Converts a frequency in Hz to a fractional MIDI note number.
+    pub fn hz_to_note(&self, hz: f64) -> f64 {
+        self.reference_note + 12.0 * (hz / self.reference_hz).log2()
+    }
+
+    /// Converts a fractional MIDI note number to a frequency in Hz.
+    pub fn note_to_hz(&self, note: f64) -> f64 {
+        self.reference_hz * ((note - self.reference_note) / 12.0).exp2()
+    }
+
+    /// Converts a fractional MIDI note number to cents relative to the reference note.
+    pub fn note_to_cents(&self, note: f64) -> f64 {
+        (note - self.reference_note) * 100.0
+    }
+
+    /// Converts cents relative to the reference note to a fractional MIDI note number.
+    pub fn cents_to_note(&self, cents: f64) -> f64 {
+        self.reference_note + cents / 100.0
+    }
+
+    /// Converts a frequency in Hz to cents relative to the reference note.
+    pub fn hz_to_cents(&self, hz: f64) -> f64 {
+        self.note_to_cents(self.hz_to_note(hz))
+    }
+
+    /// Converts cents relative to the reference note to a frequency in Hz.
+    pub fn cents_to_hz(&self, cents: f64) -> f64 {
+        self.note_to_hz(self.cents_to_note(cents))
+    }
+}
+
+/// The unit in which a [`PitchScale`] expresses its absolute values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PitchUnit {
+    /// Frequency in Hz.
+    Hz,
+    /// Fractional MIDI note number.
+    MidiNote,
+    /// Cents relative to the reference note of the tuning.
+    Cents,
+}
+
+impl PitchUnit {
+    pub(crate) fn to_note(self, value: f64, tuning: &Tuning) -> f64 {
+        match self {
+            PitchUnit::Hz => tuning.hz_to_note(value),
+            PitchUnit::MidiNote => value,
+            PitchUnit::Cents => tuning.cents_to_note(value),
+        }
+    }
+
+    pub(crate) fn in_unit(self, note: f64, tuning: &Tuning) -> f64 {
+        match self {
+            PitchUnit::Hz => tuning.note_to_hz(note),
+            PitchUnit::MidiNote => note,
+            PitchUnit::Cents => tuning.note_to_cents(note),
+        }
+    }
+}
+
+/// A scale over a range of pitches whose relative values are distributed linearly over semitones. Absolute values
+/// are expressed in the scale's [`PitchUnit`], so two pitch scales with different units but the same range can be
+/// combined in a [`Converter`](crate::prelude::Converter) to convert between Hz, MIDI notes and cents. A pitch scale
+/// in Hz is equivalent to a [`LogarithmicScale`](crate::prelude::LogarithmicScale) over the same frequency range,
+/// so the two can be combined as well.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PitchScale<N> {
+    min: N,
+    max: N,
+    unit: PitchUnit,
+    tuning: Tuning,
+    quantized: bool,
+    note_delegate: LinearScale<f64>,
+}
+
+impl<N> PitchScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Creates a pitch scale in the given unit, using the default tuning.
+    pub fn new(unit: PitchUnit, min: N, max: N) -> PitchScale<N> {
+        let tuning = Tuning::default();
+        PitchScale {
+            note_delegate: note_range(unit, &min, &max, &tuning),
+            min,
+            max,
+            unit,
+            tuning,
+            quantized: false,
+        }
+    }
+
+    pub fn hz(min: N, max: N) -> PitchScale<N> {
+        PitchScale::new(PitchUnit::Hz, min, max)
+    }
+
+    pub fn midi(min: N, max: N) -> PitchScale<N> {
+        PitchScale::new(PitchUnit::MidiNote, min, max)
+    }
+
+    pub fn cents(min: N, max: N) -> PitchScale<N> {
+        PitchScale::new(PitchUnit::Cents, min, max)
+    }
+
+    /// Like [`PitchScale::new`], but rejects empty or non-finite ranges and non-positive frequencies.
+    pub fn try_new(unit: PitchUnit, min: N, max: N) -> Result<PitchScale<N>, ScaleError> {
+        let min_f64 = min.clone().to_float();
+        let max_f64 = max.clone().to_float();
+        match unit {
+            PitchUnit::Hz => check_log_range(min_f64, max_f64)?,
+            PitchUnit::MidiNote | PitchUnit::Cents => check_range(min_f64, max_f64)?,
+        }
+        Ok(PitchScale::new(unit, min, max))
+    }
+
+    /// Uses a different tuning, keeping the bounds of the scale in its unit.
+    pub fn with_tuning(self, tuning: Tuning) -> PitchScale<N> {
+        PitchScale {
+            note_delegate: note_range(self.unit, &self.min, &self.max, &tuning),
+            tuning,
+            ..self
+        }
+    }
+
+    /// Snaps absolute values to the nearest equal-tempered semitone of the tuning.
+    pub fn quantized(self) -> PitchScale<N> {
+        PitchScale {
+            quantized: true,
+            ..self
+        }
+    }
+
+    pub fn unit(&self) -> PitchUnit {
+        self.unit
+    }
+
+    pub fn tuning(&self) -> &Tuning {
+        &self.tuning
+    }
+
+    pub fn is_quantized(&self) -> bool {
+        self.quantized
+    }
+}
+
+impl<N> Scale<N> for PitchScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let note = self.unit.to_note(absolute.to_float(), &self.tuning);
+        self.note_delegate.to_relative(note)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let note = self.note_delegate.to_absolute(relative);
+        let note = if self.quantized {
+            // semitones are counted from the reference note so detuned references stay in tune
+            let offset = self.tuning.reference_note.fract();
+            (note - offset).round() + offset
+        } else {
+            note
+        };
+        N::from_float(self.unit.in_unit(note, &self.tuning))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+fn note_range<N>(unit: PitchUnit, min: &N, max: &N, tuning: &Tuning) -> LinearScale<f64>
+where
+    N: ToFloat<f64> + Clone,
+{
+    LinearScale::new(
+        unit.to_note(min.clone().to_float(), tuning),
+        unit.to_note(max.clone().to_float(), tuning),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_tuning() {
+        let tuning = Tuning::default();
+        assert_approx_eq!(tuning.hz_to_note(440.0), 69.0);
+        assert_approx_eq!(tuning.hz_to_note(880.0), 81.0);
+        assert_approx_eq!(tuning.note_to_hz(60.0), 261.625565);
+        assert_approx_eq!(tuning.hz_to_cents(220.0), -1200.0);
+        assert_approx_eq!(tuning.cents_to_hz(100.0), 466.163762);
+
+        let baroque = Tuning::new(415.0);
+        assert_approx_eq!(baroque.hz_to_note(415.0), 69.0);
+    }
+
+    #[test]
+    fn test_hz_to_midi() {
+        let hz: PitchScale<f64> = PitchScale::hz(Tuning::default().note_to_hz(21.0), 4186.009);
+        let midi: PitchScale<f64> = PitchScale::midi(21.0, 108.0);
+
+        assert_approx_eq!((&hz, &midi).convert(440.0), 69.0, 1e-4);
+        assert_approx_eq!((&hz, &midi).convert_back(60.0), 261.625565, 1e-3);
+    }
+
+    #[test]
+    fn test_log_to_cents() {
+        let log = LogarithmicScale::new(220.0, 880.0);
+        let cents: PitchScale<f64> = PitchScale::cents(-1200.0, 1200.0);
+
+        assert_approx_eq!((&log, &cents).convert(440.0), 0f64);
+        assert_approx_eq!(
+            (&log, &cents).convert(Tuning::default().note_to_hz(70.5)),
+            150f64
+        );
+        assert_approx_eq!(
+            (&log, &cents).convert_back(-700.0),
+            Tuning::default().note_to_hz(62.0)
+        );
+    }
+
+    #[test]
+    fn test_quantized() {
+        let midi: PitchScale<f64> = PitchScale::midi(0.0, 127.0).quantized();
+        assert_approx_eq!(midi.to_absolute(69.4 / 127.0), 69.0);
+        assert_approx_eq!(midi.to_absolute(69.6 / 127.0), 70.0);
+
+        let hz: PitchScale<f64> = PitchScale::hz(220.0, 880.0).quantized();
+        let slightly_sharp = hz.to_relative(450.0);
+        assert_approx_eq!(hz.to_absolute(slightly_sharp), 440.0);
+    }
+
+    #[test]
+    fn test_custom_tuning() {
+        let hz: PitchScale<f64> = PitchScale::hz(415.0, 830.0)
+            .with_tuning(Tuning::new(415.0))
+            .quantized();
+        assert_approx_eq!(hz.to_absolute(0.0), 415.0);
+        assert_approx_eq!(
+            hz.to_absolute(0.5 / 12.0 + 0.01),
+            Tuning::new(415.0).note_to_hz(70.0)
+        );
+    }
+
+    #[test]
+    fn test_pitch_try_new() {
+        assert!(PitchScale::try_new(PitchUnit::Hz, 20.0, 20_000.0).is_ok());
+        assert!(PitchScale::try_new(PitchUnit::Cents, -1200.0, 1200.0).is_ok());
+        assert_eq!(
+            PitchScale::try_new(PitchUnit::Hz, 0.0, 20_000.0),
+            Err(ScaleError::NonPositiveLogBound)
+        );
+        assert_eq!(
+            PitchScale::try_new(PitchUnit::MidiNote, 60.0, 60.0),
+            Err(ScaleError::EmptyRange)
+        );
+    }
+
+    #[test]
+    fn test_tuning_try_new() {
+        assert_eq!(Tuning::try_new(432.0), Ok(Tuning::new(432.0)));
+        assert_eq!(
+            Tuning::try_with_reference(60.0, 261.6),
+            Ok(Tuning::with_reference(60.0, 261.6))
+        );
+        assert_eq!(Tuning::try_new(0.0), Err(ScaleError::InvalidTuning));
+        assert_eq!(Tuning::try_new(-440.0), Err(ScaleError::InvalidTuning));
+        assert_eq!(
+            Tuning::try_new(f64::INFINITY),
+            Err(ScaleError::InvalidTuning)
+        );
+        assert_eq!(
+            Tuning::try_with_reference(f64::NAN, 440.0),
+            Err(ScaleError::InvalidTuning)
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// A scale that maps `relative^exponent` onto its range, e.g. an exponent of `0.5` yields a square root
+/// taper, `2.0` a quadratic and `3.0` a cubic one. An exponent of `1.0` is equivalent to a [`LinearScale`](crate::prelude::LinearScale).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerScale<N> {
+    min: N,
+    max: N,
+    min_f64: f64,
+    full_range: f64,
+    exponent: f64,
+    inverted: bool,
+}
+
+impl<N> PowerScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(min: N, max: N, exponent: f64) -> PowerScale<N> {
+        PowerScale::create(min, max, exponent, false)
+    }
+
+    pub fn inverted(min: N, max: N, exponent: f64) -> PowerScale<N> {
+        PowerScale::create(min, max, exponent, true)
+    }
+
+    /// Like [`PowerScale::new`], but rejects empty or non-finite ranges and exponents that are not greater than zero.
+    pub fn try_new(min: N, max: N, exponent: f64) -> Result<PowerScale<N>, ScaleError> {
+        check_range(min.clone().to_float(), max.clone().to_float())?;
+        check_exponent(exponent)?;
+        Ok(PowerScale::new(min, max, exponent))
+    }
+
+    /// Like [`PowerScale::inverted`], but rejects empty or non-finite ranges and exponents that are not greater than zero.
+    pub fn try_inverted(min: N, max: N, exponent: f64) -> Result<PowerScale<N>, ScaleError> {
+        check_range(min.clone().to_float(), max.clone().to_float())?;
+        check_exponent(exponent)?;
+        Ok(PowerScale::inverted(min, max, exponent))
+    }
+
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    pub fn exponent(&self) -> f64 {
+        self.exponent
+    }
+
+    fn create(min: N, max: N, exponent: f64, inverted: bool) -> PowerScale<N> {
+        let min_f64 = min.clone().to_float();
+        let max_f64 = max.clone().to_float();
+        let full_range = max_f64 - min_f64;
+
+        PowerScale {
+            min,
+            max,
+            min_f64,
+            full_range,
+            exponent,
+            inverted,
+        }
+    }
+}
+
+impl<N> Scale<N> for PowerScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let absolute = absolute.to_float();
+        let linear = (absolute - self.min_f64) / self.full_range;
+        let relative = signed_powf(linear, 1.0 / self.exponent);
+
+        if self.inverted {
+            1.0 - relative
+        } else {
+            relative
+        }
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let relative = if self.inverted {
+            1.0 - relative
+        } else {
+            relative
+        };
+
+        let linear = signed_powf(relative, self.exponent);
+        N::from_float(self.min_f64 + linear * self.full_range)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+// mirrors the curve at the origin so that values below the minimum extrapolate instead of producing NaN
+pub(crate) fn signed_powf(value: f64, exponent: f64) -> f64 {
+    value.signum() * value.abs().powf(exponent)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_quadratic() {
+        let scale: PowerScale<f64> = PowerScale::new(0.0, 100.0, 2.0);
+        assert_approx_eq!(scale.to_absolute(0.0), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.1), 1.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 25.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 100.0);
+
+        assert_approx_eq!(scale.to_relative(0.0), 0.0);
+        assert_approx_eq!(scale.to_relative(1.0), 0.1);
+        assert_approx_eq!(scale.to_relative(25.0), 0.5);
+        assert_approx_eq!(scale.to_relative(100.0), 1.0);
+    }
+
+    #[test]
+    fn test_square_root() {
+        let scale: PowerScale<f64> = PowerScale::new(10.0, 20.0, 0.5);
+        assert_approx_eq!(scale.to_absolute(0.25), 15.0);
+        assert_approx_eq!(scale.to_relative(15.0), 0.25);
+    }
+
+    #[test]
+    fn test_cubic_inverted() {
+        let scale: PowerScale<f64> = PowerScale::inverted(0.0, 1000.0, 3.0);
+        assert_approx_eq!(scale.to_absolute(0.0), 1000.0);
+        assert_approx_eq!(scale.to_absolute(0.9), 1.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 0.0);
+
+        assert_approx_eq!(scale.to_relative(1000.0), 0.0);
+        assert_approx_eq!(scale.to_relative(1.0), 0.9);
+        assert_approx_eq!(scale.to_relative(0.0), 1.0);
+    }
+
+    #[test]
+    fn test_power_round_trip() {
+        let scale: PowerScale<f64> = PowerScale::new(-50.0, 50.0, 2.7);
+        for i in -10..=20 {
+            let relative = i as f64 * 0.1;
+            assert_approx_eq!(scale.to_relative(scale.to_absolute(relative)), relative);
+        }
+    }
+
+    #[test]
+    fn test_power_converter() {
+        let lin = LinearScale::new(0.0, 10.0);
+        let pow = PowerScale::new(0.0, 100.0, 2.0);
+
+        assert_approx_eq!((&lin, &pow).convert(5.0), 25f64);
+        assert_approx_eq!((&lin, &pow).convert_back(25.0), 5f64);
+    }
+
+    #[test]
+    fn test_power_try_new() {
+        assert!(PowerScale::try_new(0.0, 1.0, 2.0).is_ok());
+        assert_eq!(
+            PowerScale::try_new(0.0, 1.0, 0.0),
+            Err(ScaleError::InvalidExponent)
+        );
+        assert_eq!(
+            PowerScale::try_inverted(0.0, 1.0, f64::NAN),
+            Err(ScaleError::InvalidExponent)
+        );
+        assert_eq!(
+            PowerScale::try_new(1.0, 1.0, 2.0),
+            Err(ScaleError::EmptyRange)
+        );
+    }
+}
@@ -1,6 +1,25 @@
+pub use crate::any::*;
+pub use crate::bipolar::*;
 pub use crate::broken::*;
+pub use crate::circular::*;
 pub use crate::convert::*;
 pub use crate::converter::*;
+pub use crate::decibel::*;
+pub use crate::dynamic::*;
+pub use crate::error::*;
+pub use crate::format::*;
 pub use crate::linear::*;
 pub use crate::logarithmic::*;
+pub use crate::observable::*;
+pub use crate::pitch::*;
+pub use crate::power::*;
+pub use crate::psychoacoustic::*;
+pub use crate::rounded::*;
+pub use crate::shared::*;
+pub use crate::smoothing::*;
+pub use crate::spline::*;
+pub use crate::stepped::*;
+pub use crate::symlog::*;
+pub use crate::table::*;
+pub use crate::ticks::*;
 pub use crate::*;
@@ -0,0 +1,215 @@
+use super::convert::*;
+use super::error::*;
+use super::linear::*;
+use super::*;
+use std::marker::PhantomData;
+
+/// A perceptual warping of frequencies in Hz with an exact inverse.
+pub trait FrequencyWarp {
+    /// Converts a frequency in Hz to the perceptual scale.
+    fn warp(hz: f64) -> f64;
+    /// Converts a value on the perceptual scale back to a frequency in Hz.
+    fn unwarp(value: f64) -> f64;
+}
+
+/// The mel scale (O'Shaughnessy): `2595 * log10(1 + f / 700)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mel;
+
+impl FrequencyWarp for Mel {
+    fn warp(hz: f64) -> f64 {
+        2595.0 * (1.0 + hz / 700.0).log10()
+    }
+
+    fn unwarp(mel: f64) -> f64 {
+        700.0 * (10f64.powf(mel / 2595.0) - 1.0)
+    }
+}
+
+/// The Bark scale (Traunmüller): `26.81 * f / (1960 + f) - 0.53`. The usual corrections at the low and high end
+/// are omitted to keep the conversion exactly invertible.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bark;
+
+impl FrequencyWarp for Bark {
+    fn warp(hz: f64) -> f64 {
+        26.81 * hz / (1960.0 + hz) - 0.53
+    }
+
+    fn unwarp(bark: f64) -> f64 {
+        1960.0 * (bark + 0.53) / (26.28 - bark)
+    }
+}
+
+/// The ERB-rate scale (Glasberg & Moore): `21.4 * log10(1 + 0.00437 * f)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Erb;
+
+impl FrequencyWarp for Erb {
+    fn warp(hz: f64) -> f64 {
+        21.4 * (1.0 + 0.00437 * hz).log10()
+    }
+
+    fn unwarp(erb: f64) -> f64 {
+        (10f64.powf(erb / 21.4) - 1.0) / 0.00437
+    }
+}
+
+/// A scale over a range of frequencies in Hz whose relative values are distributed linearly on a perceptual scale,
+/// so that equal relative distances sound equally far apart. This matches human hearing at low frequencies far
+/// better than either a [`LinearScale`] or a [`LogarithmicScale`](crate::prelude::LogarithmicScale).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerceptualScale<N, W> {
+    min: N,
+    max: N,
+    linear_delegate: LinearScale<f64>,
+    warp: PhantomData<W>,
+}
+
+/// A [`PerceptualScale`] on the mel scale.
+pub type MelScale<N> = PerceptualScale<N, Mel>;
+
+/// A [`PerceptualScale`] on the Bark scale.
+pub type BarkScale<N> = PerceptualScale<N, Bark>;
+
+/// A [`PerceptualScale`] on the ERB-rate scale.
+pub type ErbScale<N> = PerceptualScale<N, Erb>;
+
+impl<N, W> PerceptualScale<N, W>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    W: FrequencyWarp,
+{
+    pub fn new(min: N, max: N) -> PerceptualScale<N, W> {
+        PerceptualScale {
+            linear_delegate: LinearScale::new(
+                W::warp(min.clone().to_float()),
+                W::warp(max.clone().to_float()),
+            ),
+            min,
+            max,
+            warp: PhantomData,
+        }
+    }
+
+    pub fn inverted(min: N, max: N) -> PerceptualScale<N, W> {
+        PerceptualScale {
+            linear_delegate: LinearScale::inverted(
+                W::warp(min.clone().to_float()),
+                W::warp(max.clone().to_float()),
+            ),
+            min,
+            max,
+            warp: PhantomData,
+        }
+    }
+
+    /// Like [`PerceptualScale::new`], but rejects empty or non-finite ranges and negative frequencies.
+    pub fn try_new(min: N, max: N) -> Result<PerceptualScale<N, W>, ScaleError> {
+        check_frequency_range(min.clone().to_float(), max.clone().to_float())?;
+        Ok(PerceptualScale::new(min, max))
+    }
+
+    /// Like [`PerceptualScale::inverted`], but rejects empty or non-finite ranges and negative frequencies.
+    pub fn try_inverted(min: N, max: N) -> Result<PerceptualScale<N, W>, ScaleError> {
+        check_frequency_range(min.clone().to_float(), max.clone().to_float())?;
+        Ok(PerceptualScale::inverted(min, max))
+    }
+
+    pub fn is_inverted(&self) -> bool {
+        self.linear_delegate.is_inverted()
+    }
+}
+
+impl<N, W> Scale<N> for PerceptualScale<N, W>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    W: FrequencyWarp,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.linear_delegate
+            .to_relative(W::warp(absolute.to_float()))
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        N::from_float(W::unwarp(self.linear_delegate.to_absolute(relative)))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_warps() {
+        assert_approx_eq!(Mel::warp(1000.0), 999.985537, 1e-5);
+        assert_approx_eq!(Mel::warp(700.0), 2595.0 * 2f64.log10());
+        assert_approx_eq!(Bark::warp(1000.0), 8.527432, 1e-5);
+        assert_approx_eq!(Erb::warp(1000.0), 15.621450, 1e-5);
+
+        for hz in [0.0, 20.0, 440.0, 1000.0, 15_000.0].iter() {
+            assert_approx_eq!(Mel::unwarp(Mel::warp(*hz)), *hz, 1e-9);
+            assert_approx_eq!(Bark::unwarp(Bark::warp(*hz)), *hz, 1e-9);
+            assert_approx_eq!(Erb::unwarp(Erb::warp(*hz)), *hz, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_mel_scale() {
+        let scale: MelScale<f64> = MelScale::new(0.0, 700.0);
+        let midpoint = Mel::unwarp(Mel::warp(700.0) / 2.0);
+
+        assert_approx_eq!(scale.to_absolute(0.0), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.5), midpoint);
+        assert_approx_eq!(scale.to_absolute(1.0), 700.0);
+        assert_approx_eq!(scale.to_relative(midpoint), 0.5);
+        assert!(midpoint > 280.0 && midpoint < 300.0);
+    }
+
+    #[test]
+    fn test_perceptual_round_trip() {
+        let mel: MelScale<f64> = MelScale::new(20.0, 20_000.0);
+        let bark: BarkScale<f64> = BarkScale::inverted(20.0, 15_500.0);
+        let erb: ErbScale<f64> = ErbScale::new(50.0, 8_000.0);
+
+        for i in 0..=10 {
+            let relative = i as f64 * 0.1;
+            assert_approx_eq!(mel.to_relative(mel.to_absolute(relative)), relative);
+            assert_approx_eq!(bark.to_relative(bark.to_absolute(relative)), relative);
+            assert_approx_eq!(erb.to_relative(erb.to_absolute(relative)), relative);
+        }
+
+        assert_approx_eq!(bark.to_absolute(0.0), 15_500.0);
+    }
+
+    #[test]
+    fn test_perceptual_converter() {
+        let bins = LinearScale::new(0_f64, 512.0);
+        let axis: ErbScale<f64> = ErbScale::new(20.0, 20_000.0);
+        assert_approx_eq!((&axis, &bins).convert(1000.0), 185.918218, 1e-5);
+        assert_approx_eq!((&axis, &bins).convert_back(185.918218), 1000.0, 1e-3);
+    }
+
+    #[test]
+    fn test_perceptual_try_new() {
+        assert!(MelScale::try_new(0.0, 8_000.0).is_ok());
+        assert_eq!(
+            BarkScale::try_new(-1.0, 8_000.0),
+            Err(ScaleError::NegativeFrequency)
+        );
+        assert_eq!(
+            ErbScale::try_inverted(100.0, 100.0),
+            Err(ScaleError::EmptyRange)
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// Wraps a floating point scale so it can be used for integral values that are rounded according to a
+/// configurable [`Rounding`] mode instead of being truncated, e.g. to make a `usize` parameter land on the
+/// nearest step. Values outside the range of the target type saturate; use the `try_` methods to detect that instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoundedScale<S> {
+    delegate: S,
+    rounding: Rounding,
+}
+
+impl<S> RoundedScale<S>
+where
+    S: Scale<f64>,
+{
+    pub fn new(delegate: S, rounding: Rounding) -> RoundedScale<S> {
+        RoundedScale { delegate, rounding }
+    }
+
+    pub fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+
+    pub fn delegate(&self) -> &S {
+        &self.delegate
+    }
+
+    /// Converts a relative value using a different rounding mode than the one of this scale.
+    pub fn to_absolute_with<N>(&self, relative: f64, rounding: Rounding) -> N
+    where
+        N: RoundFromFloat,
+    {
+        N::round_from_float(self.delegate.to_absolute(relative), rounding)
+    }
+
+    /// Converts a relative value, failing if the rounded result cannot be represented by the target type.
+    pub fn try_to_absolute<N>(&self, relative: f64) -> Result<N, ScaleError>
+    where
+        N: RoundFromFloat,
+    {
+        self.try_to_absolute_with(relative, self.rounding)
+    }
+
+    /// Like [`RoundedScale::try_to_absolute`], but using a different rounding mode than the one of this scale.
+    pub fn try_to_absolute_with<N>(
+        &self,
+        relative: f64,
+        rounding: Rounding,
+    ) -> Result<N, ScaleError>
+    where
+        N: RoundFromFloat,
+    {
+        N::checked_from_float(self.delegate.to_absolute(relative), rounding)
+    }
+}
+
+impl<N, S> Scale<N> for RoundedScale<S>
+where
+    N: Sub<Output = N>
+        + Add<Output = N>
+        + PartialOrd
+        + FromFloat<f64>
+        + ToFloat<f64>
+        + RoundFromFloat
+        + Clone,
+    S: Scale<f64>,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.delegate.to_relative(absolute.to_float())
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.to_absolute_with(relative, self.rounding)
+    }
+
+    fn max(&self) -> N {
+        N::round_from_float(self.delegate.max(), self.rounding)
+    }
+
+    fn min(&self) -> N {
+        N::round_from_float(self.delegate.min(), self.rounding)
+    }
+
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        let values = self
+            .delegate
+            .ticks(count)
+            .into_iter()
+            .map(|tick| (tick.value, tick.major))
+            .collect();
+        to_ticks(self, values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_rounded_linear() {
+        let truncating: LinearScale<usize> = LinearScale::new(0, 100);
+        assert_eq!(truncating.to_absolute(0.999), 99);
+
+        let scale = RoundedScale::new(LinearScale::new(0.0, 100.0), Rounding::HalfEven);
+        let value: usize = scale.to_absolute(0.999);
+        assert_eq!(value, 100);
+        let value: usize = scale.to_absolute(0.125);
+        assert_eq!(value, 12);
+        assert_approx_eq!(scale.to_relative(25usize), 0.25);
+        assert_eq!(Scale::<usize>::max(&scale), 100);
+    }
+
+    #[test]
+    fn test_rounding_per_conversion() {
+        let scale = RoundedScale::new(LinearScale::new(0.0, 10.0), Rounding::HalfEven);
+        assert_eq!(scale.to_absolute_with::<i32>(0.25, Rounding::Floor), 2);
+        assert_eq!(scale.to_absolute_with::<i32>(0.25, Rounding::Ceil), 3);
+        assert_eq!(
+            scale.to_absolute_with::<i32>(0.25, Rounding::HalfAwayFromZero),
+            3
+        );
+        let value: i32 = scale.to_absolute(0.25);
+        assert_eq!(value, 2);
+    }
+
+    #[test]
+    fn test_checked_conversion() {
+        let scale = RoundedScale::new(LinearScale::new(0.0, 255.0), Rounding::HalfEven);
+        assert_eq!(scale.try_to_absolute::<u8>(1.0), Ok(255));
+        assert_eq!(
+            scale.try_to_absolute::<u8>(1.1),
+            Err(ScaleError::NotRepresentable { value: 280.5 })
+        );
+        assert!(scale.try_to_absolute::<u8>(-0.1).is_err());
+
+        let saturated: u8 = scale.to_absolute(-0.1);
+        assert_eq!(saturated, 0);
+    }
+
+    #[test]
+    fn test_rounded_converter() {
+        let slider = LinearScale::new(0.0, 1.0);
+        let steps = RoundedScale::new(LinearScale::new(0.0, 8.0), Rounding::HalfEven);
+        let value: u32 = (&slider, &steps).convert(0.99);
+        assert_eq!(value, 8);
+    }
+}
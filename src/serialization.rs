@@ -0,0 +1,1005 @@
+use super::any::*;
+use super::bipolar::*;
+use super::broken::*;
+use super::convert::*;
+use super::decibel::*;
+use super::error::*;
+use super::linear::*;
+use super::logarithmic::*;
+use super::pitch::*;
+use super::power::*;
+use super::psychoacoustic::*;
+use super::rounded::*;
+use super::spline::*;
+use super::stepped::*;
+use super::symlog::*;
+use super::table::*;
+use super::*;
+use crate::circular::*;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// The serialized form of all scales. Scales are (de)serialized through this enum rather than by deriving the traits
+// on the scales themselves, so that only their defining parameters end up in the output, the type of scale is tagged
+// and deserialized scales are validated by the same checks as the `try_*` constructors.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ScaleRepr<N> {
+    Linear {
+        min: N,
+        max: N,
+        #[serde(default, skip_serializing_if = "is_false")]
+        inverted: bool,
+    },
+    Log {
+        min: N,
+        max: N,
+        #[serde(default, skip_serializing_if = "is_false")]
+        inverted: bool,
+        #[serde(default, skip_serializing_if = "is_false")]
+        approximate: bool,
+    },
+    Power {
+        min: N,
+        max: N,
+        exponent: f64,
+        #[serde(default, skip_serializing_if = "is_false")]
+        inverted: bool,
+    },
+    #[serde(rename = "symlog")]
+    SymLog {
+        min: N,
+        max: N,
+        threshold: f64,
+        #[serde(default, skip_serializing_if = "is_false")]
+        inverted: bool,
+    },
+    Circular {
+        min: N,
+        max: N,
+        #[serde(default, skip_serializing_if = "is_false")]
+        inverted: bool,
+    },
+    Mel {
+        min: N,
+        max: N,
+        #[serde(default, skip_serializing_if = "is_false")]
+        inverted: bool,
+    },
+    Bark {
+        min: N,
+        max: N,
+        #[serde(default, skip_serializing_if = "is_false")]
+        inverted: bool,
+    },
+    Erb {
+        min: N,
+        max: N,
+        #[serde(default, skip_serializing_if = "is_false")]
+        inverted: bool,
+    },
+    Broken {
+        min: N,
+        max: N,
+        steps: Vec<(N, f64)>,
+        #[serde(default, skip_serializing_if = "all_linear")]
+        curves: Vec<Curve>,
+    },
+    Spline {
+        points: Vec<(N, f64)>,
+    },
+    Table {
+        samples: Vec<(N, f64)>,
+    },
+    Stepped {
+        values: Vec<N>,
+    },
+    Enum {
+        options: Vec<N>,
+    },
+    Pitch {
+        unit: PitchUnit,
+        min: N,
+        max: N,
+        #[serde(default, skip_serializing_if = "is_default_tuning")]
+        tuning: Tuning,
+        #[serde(default, skip_serializing_if = "is_false")]
+        quantized: bool,
+    },
+    #[serde(rename = "db")]
+    Decibel {
+        #[serde(default = "silence", skip_serializing_if = "is_silence")]
+        min_db: f64,
+        max_db: f64,
+        #[serde(default = "amplitude")]
+        unit: DecibelUnit,
+    },
+    Fader {
+        #[serde(default = "silence", skip_serializing_if = "is_silence")]
+        min_db: f64,
+        max_db: f64,
+        steps: Vec<(f64, f64)>,
+        #[serde(default, skip_serializing_if = "all_linear")]
+        curves: Vec<Curve>,
+        #[serde(default = "amplitude")]
+        unit: DecibelUnit,
+    },
+    Bipolar {
+        lower: Box<ScaleRepr<N>>,
+        upper: Box<ScaleRepr<N>>,
+        #[serde(default, skip_serializing_if = "is_zero")]
+        detent: f64,
+    },
+    Rounded {
+        delegate: Box<ScaleRepr<f64>>,
+        rounding: Rounding,
+    },
+}
+
+impl<N> ScaleRepr<N> {
+    fn name(&self) -> &'static str {
+        match self {
+            ScaleRepr::Linear { .. } => "linear",
+            ScaleRepr::Log { .. } => "log",
+            ScaleRepr::Power { .. } => "power",
+            ScaleRepr::SymLog { .. } => "symlog",
+            ScaleRepr::Circular { .. } => "circular",
+            ScaleRepr::Mel { .. } => "mel",
+            ScaleRepr::Bark { .. } => "bark",
+            ScaleRepr::Erb { .. } => "erb",
+            ScaleRepr::Broken { .. } => "broken",
+            ScaleRepr::Spline { .. } => "spline",
+            ScaleRepr::Table { .. } => "table",
+            ScaleRepr::Stepped { .. } => "stepped",
+            ScaleRepr::Enum { .. } => "enum",
+            ScaleRepr::Pitch { .. } => "pitch",
+            ScaleRepr::Decibel { .. } => "db",
+            ScaleRepr::Fader { .. } => "fader",
+            ScaleRepr::Bipolar { .. } => "bipolar",
+            ScaleRepr::Rounded { .. } => "rounded",
+        }
+    }
+
+    fn unexpected<T>(self, expected: &'static str) -> Result<T, ScaleError> {
+        Err(ScaleError::UnexpectedScaleType {
+            expected,
+            actual: self.name(),
+        })
+    }
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+fn is_zero(value: &f64) -> bool {
+    *value == 0.0
+}
+
+fn all_linear(curves: &[Curve]) -> bool {
+    curves.iter().all(|curve| curve == &Curve::Linear)
+}
+
+fn is_default_tuning(tuning: &Tuning) -> bool {
+    tuning == &Tuning::default()
+}
+
+fn amplitude() -> DecibelUnit {
+    DecibelUnit::Amplitude
+}
+
+// Decibel ranges starting at negative infinity omit their lower bound, since JSON can't represent infinity.
+fn silence() -> f64 {
+    f64::NEG_INFINITY
+}
+
+fn is_silence(min_db: &f64) -> bool {
+    *min_db == f64::NEG_INFINITY
+}
+
+trait Repr<N>: Sized {
+    fn to_repr(&self) -> ScaleRepr<N>;
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError>;
+}
+
+// Ties the value type of a scale to the scale, so that wrappers like `BipolarScale` can pick the serialized form of
+// their wrapped scales.
+trait Values {
+    type N;
+}
+
+macro_rules! serde_via_repr {
+    ($($scale:ident),*) => {
+        $(
+            impl<N> Serialize for $scale<N>
+            where
+                N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone + Serialize,
+            {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.to_repr().serialize(serializer)
+                }
+            }
+
+            impl<'de, N> Deserialize<'de> for $scale<N>
+            where
+                N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone + Deserialize<'de>,
+            {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    $scale::from_repr(ScaleRepr::deserialize(deserializer)?).map_err(D::Error::custom)
+                }
+            }
+
+            impl<N> Values for $scale<N>
+            where
+                N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+            {
+                type N = N;
+            }
+        )*
+    };
+}
+
+serde_via_repr!(
+    AnyScale,
+    LinearScale,
+    LogarithmicScale,
+    PowerScale,
+    SymLogScale,
+    CircularScale,
+    MelScale,
+    BarkScale,
+    ErbScale,
+    BrokenScale,
+    SplineScale,
+    TableScale,
+    SteppedScale,
+    PitchScale,
+    DecibelScale,
+    FaderScale
+);
+
+// Scales that are only defined by their bounds and whether they are inverted.
+macro_rules! bounded_repr {
+    ($($scale:ident => $variant:ident($name:literal)),*) => {
+        $(
+            impl<N> Repr<N> for $scale<N>
+            where
+                N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+            {
+                fn to_repr(&self) -> ScaleRepr<N> {
+                    ScaleRepr::$variant {
+                        min: self.min(),
+                        max: self.max(),
+                        inverted: self.is_inverted(),
+                    }
+                }
+
+                fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+                    match repr {
+                        ScaleRepr::$variant { min, max, inverted: false } => $scale::try_new(min, max),
+                        ScaleRepr::$variant { min, max, inverted: true } => $scale::try_inverted(min, max),
+                        other => other.unexpected($name),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+bounded_repr!(
+    LinearScale => Linear("linear"),
+    CircularScale => Circular("circular"),
+    MelScale => Mel("mel"),
+    BarkScale => Bark("bark"),
+    ErbScale => Erb("erb")
+);
+
+impl<N> Repr<N> for LogarithmicScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        ScaleRepr::Log {
+            min: self.min(),
+            max: self.max(),
+            inverted: self.is_inverted(),
+            approximate: self.is_approximate(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::Log {
+                min,
+                max,
+                inverted,
+                approximate,
+            } => {
+                let scale = if inverted {
+                    LogarithmicScale::try_inverted(min, max)?
+                } else {
+                    LogarithmicScale::try_new(min, max)?
+                };
+                Ok(if approximate {
+                    scale.approximate()
+                } else {
+                    scale
+                })
+            }
+            other => other.unexpected("log"),
+        }
+    }
+}
+
+impl<N> Repr<N> for PowerScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        ScaleRepr::Power {
+            min: self.min(),
+            max: self.max(),
+            exponent: self.exponent(),
+            inverted: self.is_inverted(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::Power {
+                min,
+                max,
+                exponent,
+                inverted: false,
+            } => PowerScale::try_new(min, max, exponent),
+            ScaleRepr::Power {
+                min,
+                max,
+                exponent,
+                inverted: true,
+            } => PowerScale::try_inverted(min, max, exponent),
+            other => other.unexpected("power"),
+        }
+    }
+}
+
+impl<N> Repr<N> for SymLogScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        ScaleRepr::SymLog {
+            min: self.min(),
+            max: self.max(),
+            threshold: self.threshold(),
+            inverted: self.is_inverted(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::SymLog {
+                min,
+                max,
+                threshold,
+                inverted: false,
+            } => SymLogScale::try_new(min, max, threshold),
+            ScaleRepr::SymLog {
+                min,
+                max,
+                threshold,
+                inverted: true,
+            } => SymLogScale::try_inverted(min, max, threshold),
+            other => other.unexpected("symlog"),
+        }
+    }
+}
+
+impl<N> Repr<N> for BrokenScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        ScaleRepr::Broken {
+            min: self.min(),
+            max: self.max(),
+            steps: self.steps(),
+            curves: self.curves().to_vec(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::Broken {
+                min,
+                max,
+                steps,
+                curves,
+            } if curves.is_empty() => BrokenScale::try_new(min, max, &steps),
+            ScaleRepr::Broken {
+                min,
+                max,
+                steps,
+                curves,
+            } => BrokenScale::try_with_curves(min, max, &steps, &curves),
+            other => other.unexpected("broken"),
+        }
+    }
+}
+
+impl<N> Repr<N> for SplineScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        ScaleRepr::Spline {
+            points: self.points(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::Spline { points } => SplineScale::try_new(&points),
+            other => other.unexpected("spline"),
+        }
+    }
+}
+
+impl<N> Repr<N> for TableScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        ScaleRepr::Table {
+            samples: self.samples(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::Table { samples } => TableScale::try_new(&samples),
+            other => other.unexpected("table"),
+        }
+    }
+}
+
+impl<N> Repr<N> for SteppedScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        ScaleRepr::Stepped {
+            values: self.values().to_vec(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::Stepped { values } => SteppedScale::try_new(&values),
+            other => other.unexpected("stepped"),
+        }
+    }
+}
+
+impl<N> Repr<N> for PitchScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        ScaleRepr::Pitch {
+            unit: self.unit(),
+            min: self.min(),
+            max: self.max(),
+            tuning: *self.tuning(),
+            quantized: self.is_quantized(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::Pitch {
+                unit,
+                min,
+                max,
+                tuning,
+                quantized,
+            } => {
+                let scale = PitchScale::try_new(unit, min, max)?.with_tuning(tuning);
+                Ok(if quantized { scale.quantized() } else { scale })
+            }
+            other => other.unexpected("pitch"),
+        }
+    }
+}
+
+impl<N> Repr<N> for DecibelScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        ScaleRepr::Decibel {
+            min_db: self.min_db(),
+            max_db: self.max_db(),
+            unit: self.unit(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::Decibel {
+                min_db,
+                max_db,
+                unit: DecibelUnit::Amplitude,
+            } => DecibelScale::try_new(min_db, max_db),
+            ScaleRepr::Decibel {
+                min_db,
+                max_db,
+                unit: DecibelUnit::Power,
+            } => DecibelScale::try_power(min_db, max_db),
+            other => other.unexpected("db"),
+        }
+    }
+}
+
+impl<N> Repr<N> for FaderScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        let delegate = self.db_delegate();
+        ScaleRepr::Fader {
+            min_db: self.min_db(),
+            max_db: self.max_db(),
+            steps: delegate.steps(),
+            curves: delegate.curves().to_vec(),
+            unit: self.unit(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::Fader {
+                min_db,
+                max_db,
+                steps,
+                curves,
+                unit,
+            } => FaderScale::try_from_parts(min_db, max_db, &steps, &curves, unit),
+            other => other.unexpected("fader"),
+        }
+    }
+}
+
+impl<N> Repr<N> for AnyScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        match self {
+            AnyScale::Linear(scale) => scale.to_repr(),
+            AnyScale::Log(scale) => scale.to_repr(),
+            AnyScale::Power(scale) => scale.to_repr(),
+            AnyScale::SymLog(scale) => scale.to_repr(),
+            AnyScale::Circular(scale) => scale.to_repr(),
+            AnyScale::Mel(scale) => scale.to_repr(),
+            AnyScale::Bark(scale) => scale.to_repr(),
+            AnyScale::Erb(scale) => scale.to_repr(),
+            AnyScale::Broken(scale) => scale.to_repr(),
+            AnyScale::Spline(scale) => scale.to_repr(),
+            AnyScale::Table(scale) => scale.to_repr(),
+            AnyScale::Stepped(scale) => scale.to_repr(),
+            AnyScale::Pitch(scale) => scale.to_repr(),
+            AnyScale::Decibel(scale) => scale.to_repr(),
+            AnyScale::Fader(scale) => scale.to_repr(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        Ok(match repr {
+            ScaleRepr::Linear { .. } => AnyScale::Linear(LinearScale::from_repr(repr)?),
+            ScaleRepr::Log { .. } => AnyScale::Log(LogarithmicScale::from_repr(repr)?),
+            ScaleRepr::Power { .. } => AnyScale::Power(PowerScale::from_repr(repr)?),
+            ScaleRepr::SymLog { .. } => AnyScale::SymLog(SymLogScale::from_repr(repr)?),
+            ScaleRepr::Circular { .. } => AnyScale::Circular(CircularScale::from_repr(repr)?),
+            ScaleRepr::Mel { .. } => AnyScale::Mel(MelScale::from_repr(repr)?),
+            ScaleRepr::Bark { .. } => AnyScale::Bark(BarkScale::from_repr(repr)?),
+            ScaleRepr::Erb { .. } => AnyScale::Erb(ErbScale::from_repr(repr)?),
+            ScaleRepr::Broken { .. } => AnyScale::Broken(BrokenScale::from_repr(repr)?),
+            ScaleRepr::Spline { .. } => AnyScale::Spline(SplineScale::from_repr(repr)?),
+            ScaleRepr::Table { .. } => AnyScale::Table(TableScale::from_repr(repr)?),
+            ScaleRepr::Stepped { .. } => AnyScale::Stepped(SteppedScale::from_repr(repr)?),
+            ScaleRepr::Pitch { .. } => AnyScale::Pitch(PitchScale::from_repr(repr)?),
+            ScaleRepr::Decibel { .. } => AnyScale::Decibel(DecibelScale::from_repr(repr)?),
+            ScaleRepr::Fader { .. } => AnyScale::Fader(FaderScale::from_repr(repr)?),
+            ScaleRepr::Enum { .. } => return repr.unexpected("any scale of numbers"),
+            ScaleRepr::Bipolar { .. } | ScaleRepr::Rounded { .. } => {
+                return repr.unexpected("a scale that does not wrap other scales")
+            }
+        })
+    }
+}
+
+impl<N, L, U> Repr<N> for BipolarScale<L, U>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    L: Repr<N> + Scale<N>,
+    U: Repr<N> + Scale<N>,
+{
+    fn to_repr(&self) -> ScaleRepr<N> {
+        ScaleRepr::Bipolar {
+            lower: Box::new(self.lower().to_repr()),
+            upper: Box::new(self.upper().to_repr()),
+            detent: self.detent(),
+        }
+    }
+
+    fn from_repr(repr: ScaleRepr<N>) -> Result<Self, ScaleError> {
+        match repr {
+            ScaleRepr::Bipolar {
+                lower,
+                upper,
+                detent,
+            } => BipolarScale::try_new(L::from_repr(*lower)?, U::from_repr(*upper)?)?
+                .try_with_detent(detent),
+            other => other.unexpected("bipolar"),
+        }
+    }
+}
+
+impl<L, U> Values for BipolarScale<L, U>
+where
+    L: Values,
+{
+    type N = L::N;
+}
+
+impl<L, U> Serialize for BipolarScale<L, U>
+where
+    L: Values + Repr<L::N> + Scale<L::N>,
+    U: Repr<L::N> + Scale<L::N>,
+    L::N: Sub<Output = L::N>
+        + Add<Output = L::N>
+        + PartialOrd
+        + FromFloat<f64>
+        + ToFloat<f64>
+        + Clone
+        + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_repr().serialize(serializer)
+    }
+}
+
+impl<'de, L, U> Deserialize<'de> for BipolarScale<L, U>
+where
+    L: Values + Repr<L::N> + Scale<L::N>,
+    U: Repr<L::N> + Scale<L::N>,
+    L::N: Sub<Output = L::N>
+        + Add<Output = L::N>
+        + PartialOrd
+        + FromFloat<f64>
+        + ToFloat<f64>
+        + Clone
+        + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        BipolarScale::from_repr(ScaleRepr::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+// Rounded scales wrap a floating point scale, so they are serialized the same way for all integral value types.
+impl<S> Serialize for RoundedScale<S>
+where
+    S: Repr<f64> + Scale<f64>,
+{
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        ScaleRepr::<f64>::Rounded {
+            delegate: Box::new(self.delegate().to_repr()),
+            rounding: self.rounding(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, S> Deserialize<'de> for RoundedScale<S>
+where
+    S: Repr<f64> + Scale<f64>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match ScaleRepr::<f64>::deserialize(deserializer)? {
+            ScaleRepr::Rounded { delegate, rounding } => {
+                S::from_repr(*delegate).map(|delegate| RoundedScale::new(delegate, rounding))
+            }
+            other => other.unexpected("rounded"),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+impl<T> Serialize for EnumScale<T>
+where
+    T: Clone + PartialEq + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ScaleRepr::Enum {
+            options: self.options().to_vec(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for EnumScale<T>
+where
+    T: Clone + PartialEq + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match ScaleRepr::deserialize(deserializer)? {
+            ScaleRepr::Enum { options } => EnumScale::try_new(&options),
+            other => other.unexpected("enum"),
+        }
+        .map_err(D::Error::custom)
+    }
+}
+
+// The serialized form of a tuning, which is deserialized through the same checks as `Tuning::try_with_reference`.
+#[derive(Deserialize)]
+struct TuningRepr {
+    reference_note: f64,
+    reference_hz: f64,
+}
+
+impl<'de> Deserialize<'de> for Tuning {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let TuningRepr {
+            reference_note,
+            reference_hz,
+        } = TuningRepr::deserialize(deserializer)?;
+        Tuning::try_with_reference(reference_note, reference_hz).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_tagged_representation() {
+        let log: LogarithmicScale<f64> = LogarithmicScale::new(20.0, 20000.0);
+        assert_eq!(
+            serde_json::to_value(&log).unwrap(),
+            json!({"type": "log", "min": 20.0, "max": 20000.0})
+        );
+
+        let inverted: LinearScale<i32> = LinearScale::inverted(0, 127);
+        assert_eq!(
+            serde_json::to_value(&inverted).unwrap(),
+            json!({"type": "linear", "min": 0, "max": 127, "inverted": true})
+        );
+
+        let parsed: LogarithmicScale<f64> =
+            serde_json::from_str(r#"{"type": "log", "min": 20, "max": 20000}"#).unwrap();
+        assert_eq!(parsed, log);
+    }
+
+    #[test]
+    fn test_broken_steps_are_preserved() {
+        let integral: BrokenScale<i32> = BrokenScale::new(0, 100, &[(29, 0.5)]);
+        let json = serde_json::to_value(&integral).unwrap();
+        assert_eq!(
+            json,
+            json!({"type": "broken", "min": 0, "max": 100, "steps": [[29, 0.5]]})
+        );
+        assert_eq!(
+            serde_json::from_value::<BrokenScale<i32>>(json).unwrap(),
+            integral
+        );
+
+        let inexact: BrokenScale<f64> =
+            BrokenScale::new(-120.0, 12.0, &[(-60.1, 0.25), (2.9, 0.9)]);
+        let json = serde_json::to_value(&inexact).unwrap();
+        assert_eq!(json["steps"], json!([[-60.1, 0.25], [2.9, 0.9]]));
+        assert_eq!(
+            serde_json::from_value::<BrokenScale<f64>>(json).unwrap(),
+            inexact
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        fn round_trip<S>(scale: S)
+        where
+            S: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+        {
+            let json = serde_json::to_string(&scale).unwrap();
+            assert_eq!(serde_json::from_str::<S>(&json).unwrap(), scale);
+        }
+
+        round_trip(LinearScale::new(-10.0, 10.0));
+        round_trip(LogarithmicScale::inverted(1.0, 1000.0));
+        round_trip(LogarithmicScale::new(20.0, 20000.0).approximate());
+        round_trip(PowerScale::new(0.0, 100.0, 2.0));
+        round_trip(SymLogScale::new(-100.0, 100.0, 1.0));
+        round_trip(CircularScale::new(0.0, 360.0));
+        round_trip(MelScale::new(20.0, 20000.0));
+        round_trip(BarkScale::inverted(20.0, 20000.0));
+        round_trip(BrokenScale::with_curves(
+            0.0,
+            100.0,
+            &[(25.0, 0.5)],
+            &[Curve::Power(2.0), Curve::Hold],
+        ));
+        round_trip(SplineScale::new(&[(0.0, 0.0), (10.0, 0.5), (100.0, 1.0)]));
+        round_trip(TableScale::new(&[(0.0, 0.0), (10.0, 0.5), (100.0, 1.0)]));
+        round_trip(SteppedScale::new(&[1, 2, 4, 8]));
+        round_trip(EnumScale::new(&["sine".to_owned(), "saw".to_owned()]));
+        round_trip(PitchScale::midi(0.0, 127.0).with_tuning(Tuning::new(432.0)));
+        round_trip(DecibelScale::<f64>::power(-60.0, 0.0));
+        round_trip(FaderScale::<f64>::console_fader());
+        round_trip(BipolarScale::linear(-100, 0, 50).with_detent(0.1));
+        round_trip(BipolarScale::<AnyScale<f64>, AnyScale<f64>>::new(
+            LogarithmicScale::inverted(20.0, 1000.0).into(),
+            LinearScale::new(1000.0, 2000.0).into(),
+        ));
+        round_trip(RoundedScale::new(
+            LogarithmicScale::new(1.0, 1000.0),
+            Rounding::HalfEven,
+        ));
+    }
+
+    #[test]
+    fn test_wrapped_scales() {
+        let bipolar = BipolarScale::linear(-12.0, 0.0, 6.0);
+        assert_eq!(
+            serde_json::to_value(&bipolar).unwrap(),
+            json!({
+                "type": "bipolar",
+                "lower": {"type": "linear", "min": -12.0, "max": 0.0},
+                "upper": {"type": "linear", "min": 0.0, "max": 6.0}
+            })
+        );
+
+        let rounded = RoundedScale::new(LinearScale::new(0.0, 10.0), Rounding::Floor);
+        assert_eq!(
+            serde_json::to_value(&rounded).unwrap(),
+            json!({
+                "type": "rounded",
+                "delegate": {"type": "linear", "min": 0.0, "max": 10.0},
+                "rounding": "floor"
+            })
+        );
+
+        let mismatch =
+            serde_json::from_value::<BipolarScale<LinearScale<f64>, LinearScale<f64>>>(json!({
+                "type": "bipolar",
+                "lower": {"type": "linear", "min": -12, "max": 0},
+                "upper": {"type": "linear", "min": 1, "max": 6}
+            }));
+        assert_eq!(
+            mismatch.unwrap_err().to_string(),
+            ScaleError::CentreMismatch.to_string()
+        );
+
+        let detent =
+            serde_json::from_value::<BipolarScale<LinearScale<f64>, LinearScale<f64>>>(json!({
+                "type": "bipolar",
+                "lower": {"type": "linear", "min": -12, "max": 0},
+                "upper": {"type": "linear", "min": 0, "max": 6},
+                "detent": 1.5
+            }));
+        assert_eq!(
+            detent.unwrap_err().to_string(),
+            ScaleError::InvalidDetent.to_string()
+        );
+
+        let any = serde_json::from_value::<AnyScale<f64>>(serde_json::to_value(&bipolar).unwrap());
+        assert!(any.is_err());
+    }
+
+    #[test]
+    fn test_validates_on_deserialize() {
+        let empty =
+            serde_json::from_str::<LinearScale<f64>>(r#"{"type": "linear", "min": 1, "max": 1}"#);
+        assert_eq!(
+            empty.unwrap_err().to_string(),
+            ScaleError::EmptyRange.to_string()
+        );
+
+        let negative = serde_json::from_str::<LogarithmicScale<f64>>(
+            r#"{"type": "log", "min": -1, "max": 1}"#,
+        );
+        assert!(negative.is_err());
+
+        let wrong_type = serde_json::from_str::<LogarithmicScale<f64>>(
+            r#"{"type": "linear", "min": 1, "max": 10}"#,
+        );
+        assert_eq!(
+            wrong_type.unwrap_err().to_string(),
+            ScaleError::UnexpectedScaleType {
+                expected: "log",
+                actual: "linear"
+            }
+            .to_string()
+        );
+
+        let unsorted = serde_json::from_str::<BrokenScale<f64>>(
+            r#"{"type": "broken", "min": 0, "max": 100, "steps": [[50, 0.5], [25, 0.75]]}"#,
+        );
+        assert!(unsorted.is_err());
+
+        let silent = serde_json::from_str::<Tuning>(r#"{"reference_note": 69, "reference_hz": 0}"#);
+        assert_eq!(
+            silent.unwrap_err().to_string(),
+            ScaleError::InvalidTuning.to_string()
+        );
+
+        let detuned = serde_json::from_str::<PitchScale<f64>>(
+            r#"{"type": "pitch", "unit": "hz", "min": 20, "max": 20000,
+                "tuning": {"reference_note": 69, "reference_hz": -440}}"#,
+        );
+        assert!(detuned
+            .unwrap_err()
+            .to_string()
+            .starts_with(&ScaleError::InvalidTuning.to_string()));
+
+        let tuning: Tuning =
+            serde_json::from_str(r#"{"reference_note": 60, "reference_hz": 261.5}"#).unwrap();
+        assert_eq!(tuning, Tuning::with_reference(60.0, 261.5));
+    }
+
+    #[test]
+    fn test_any_scale_from_config() {
+        let scales: Vec<AnyScale<f64>> = serde_json::from_str(
+            r#"[{"type": "log", "min": 20, "max": 20000}, {"type": "power", "min": 0, "max": 100, "exponent": 2}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            scales[0],
+            AnyScale::Log(LogarithmicScale::new(20.0, 20000.0))
+        );
+        assert_approx_eq!(scales[1].to_absolute(0.5), 25f64);
+        assert_eq!(
+            serde_json::to_value(&scales[0]).unwrap(),
+            json!({"type": "log", "min": 20.0, "max": 20000.0})
+        );
+
+        let options =
+            serde_json::from_str::<AnyScale<f64>>(r#"{"type": "enum", "options": [1, 2]}"#);
+        assert!(options.is_err());
+    }
+
+    #[test]
+    fn test_deserialized_fader() {
+        let fader: FaderScale<f64> = serde_json::from_str(
+            r#"{"type": "fader", "min_db": -60, "max_db": 10, "steps": [[0, 0.75]]}"#,
+        )
+        .unwrap();
+        assert_approx_eq!(fader.to_absolute(0.75), 1f64);
+        assert_eq!(fader.unit(), DecibelUnit::Amplitude);
+    }
+
+    #[test]
+    fn test_decibel_range_from_silence() {
+        let scale: DecibelScale<f64> = DecibelScale::new(f64::NEG_INFINITY, 10.0);
+        let json = serde_json::to_value(&scale).unwrap();
+        assert_eq!(
+            json,
+            json!({"type": "db", "max_db": 10.0, "unit": "amplitude"})
+        );
+        assert_eq!(
+            serde_json::from_value::<DecibelScale<f64>>(json).unwrap(),
+            scale
+        );
+
+        let fader: FaderScale<f64> = FaderScale::fader(f64::NEG_INFINITY, 10.0, &[(0.0, 0.75)]);
+        let json = serde_json::to_string(&fader).unwrap();
+        assert_eq!(
+            serde_json::from_str::<FaderScale<f64>>(&json).unwrap(),
+            fader
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use super::convert::*;
+use super::linear::*;
+use super::logarithmic::*;
+use super::*;
+use std::fmt;
+use std::hint;
+use std::marker::PhantomData;
+use std::sync::atomic::{fence, AtomicU64, AtomicUsize, Ordering};
+
+/// A scale whose bounds can be changed from one thread while other threads use it for conversions, e.g. to retune a
+/// parameter range from the UI thread while the audio thread reads it. Wrap it in an [`Arc`] to share it.
+///
+/// The bounds are stored in atomics guarded by a sequence counter, so readers never observe the minimum of one update
+/// combined with the maximum of another. Readers don't take a lock, but they are not wait-free either: a read that
+/// overlaps an update spins until the update is done, so bounds should not be updated in a tight loop while a
+/// real-time thread reads them. The bounds are stored as `f64`, so integral bounds beyond 2^53 lose precision.
+///
+/// Like a [`DynamicScale`](crate::prelude::DynamicScale) it rebuilds the actual scale from the current bounds for
+/// every calculation using `build`, which should therefore be cheap and must not allocate if it is used on a
+/// real-time thread.
+pub struct SharedScale<N, B> {
+    sequence: AtomicUsize,
+    min: AtomicU64,
+    max: AtomicU64,
+    build: B,
+    value_type: PhantomData<fn() -> N>,
+}
+
+impl<N, B> SharedScale<N, B>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(min: N, max: N, build: B) -> SharedScale<N, B> {
+        SharedScale {
+            sequence: AtomicUsize::new(0),
+            min: AtomicU64::new(min.to_float().to_bits()),
+            max: AtomicU64::new(max.to_float().to_bits()),
+            build,
+            value_type: PhantomData,
+        }
+    }
+
+    /// Reads a consistent snapshot of the current bounds.
+    pub fn bounds(&self) -> (N, N) {
+        let (min, max) = self.read();
+        (N::from_float(min), N::from_float(max))
+    }
+
+    /// Atomically replaces both bounds.
+    pub fn set_bounds(&self, min: N, max: N) {
+        self.write(|_, _| (min.to_float(), max.to_float()));
+    }
+
+    /// Atomically replaces the minimum, keeping the current maximum.
+    pub fn set_min(&self, min: N) {
+        self.write(|_, max| (min.to_float(), max));
+    }
+
+    /// Atomically replaces the maximum, keeping the current minimum.
+    pub fn set_max(&self, max: N) {
+        self.write(|min, _| (min, max.to_float()));
+    }
+
+    fn read(&self) -> (f64, f64) {
+        loop {
+            let before = self.sequence.load(Ordering::Acquire);
+            if before & 1 == 0 {
+                let min = self.min.load(Ordering::Relaxed);
+                let max = self.max.load(Ordering::Relaxed);
+                fence(Ordering::Acquire);
+                if self.sequence.load(Ordering::Relaxed) == before {
+                    return (f64::from_bits(min), f64::from_bits(max));
+                }
+            }
+            hint::spin_loop();
+        }
+    }
+
+    fn write(&self, update: impl FnOnce(f64, f64) -> (f64, f64)) {
+        // an odd sequence number marks a write in progress and keeps other writers out
+        let sequence = loop {
+            let current = self.sequence.load(Ordering::Relaxed);
+            if current & 1 == 0
+                && self
+                    .sequence
+                    .compare_exchange_weak(
+                        current,
+                        current + 1,
+                        Ordering::Acquire,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+            {
+                break current;
+            }
+            hint::spin_loop();
+        };
+        fence(Ordering::Release);
+
+        let min = f64::from_bits(self.min.load(Ordering::Relaxed));
+        let max = f64::from_bits(self.max.load(Ordering::Relaxed));
+        let (min, max) = update(min, max);
+        self.min.store(min.to_bits(), Ordering::Relaxed);
+        self.max.store(max.to_bits(), Ordering::Relaxed);
+
+        self.sequence.store(sequence + 2, Ordering::Release);
+    }
+}
+
+impl<N> SharedScale<N, fn(N, N) -> LinearScale<N>>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn linear(min: N, max: N) -> Self {
+        SharedScale::new(min, max, LinearScale::new)
+    }
+}
+
+impl<N> SharedScale<N, fn(N, N) -> LogarithmicScale<N>>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn logarithmic(min: N, max: N) -> Self {
+        SharedScale::new(min, max, LogarithmicScale::new)
+    }
+}
+
+impl<N, S, B> SharedScale<N, B>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+    B: Fn(N, N) -> S,
+{
+    /// Builds the scale from a consistent snapshot of the current bounds.
+    pub fn current(&self) -> S {
+        let (min, max) = self.bounds();
+        let build = &self.build;
+        build(min, max)
+    }
+}
+
+impl<N, S, B> Scale<N> for SharedScale<N, B>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+    B: Fn(N, N) -> S,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        self.current().to_relative(absolute)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.current().to_absolute(relative)
+    }
+
+    fn max(&self) -> N {
+        self.bounds().1
+    }
+
+    fn min(&self) -> N {
+        self.bounds().0
+    }
+
+    fn to_relative_delta(&self, absolute_delta: N, relative_pos: f64) -> f64 {
+        self.current()
+            .to_relative_delta(absolute_delta, relative_pos)
+    }
+
+    fn to_absolute_delta(&self, relative_delta: f64, absolute_pos: N) -> N {
+        self.current()
+            .to_absolute_delta(relative_delta, absolute_pos)
+    }
+
+    fn ticks(&self, count: usize) -> Vec<Tick<N>> {
+        self.current().ticks(count)
+    }
+
+    fn to_relative_slice(&self, absolute: &[N], relative: &mut [f64]) {
+        self.current().to_relative_slice(absolute, relative)
+    }
+
+    fn to_absolute_slice(&self, relative: &[f64], absolute: &mut [N]) {
+        self.current().to_absolute_slice(relative, absolute)
+    }
+
+    fn to_relative_in_place(&self, values: &mut [N]) {
+        self.current().to_relative_in_place(values)
+    }
+
+    fn to_absolute_in_place(&self, values: &mut [N]) {
+        self.current().to_absolute_in_place(values)
+    }
+}
+
+impl<N, B> fmt::Debug for SharedScale<N, B>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (min, max) = self.read();
+        f.debug_struct("SharedScale")
+            .field("min", &min)
+            .field("max", &max)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+    use std::thread;
+
+    #[test]
+    fn test_shared_bounds() {
+        let scale = SharedScale::linear(0_f64, 100.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 50.0);
+
+        scale.set_max(200.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 100.0);
+
+        scale.set_min(100.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 150.0);
+        assert_eq!(scale.bounds(), (100.0, 200.0));
+
+        scale.set_bounds(10.0, 20.0);
+        assert_approx_eq!(scale.min(), 10.0);
+        assert_approx_eq!(scale.max(), 20.0);
+    }
+
+    #[test]
+    fn test_shared_logarithmic() {
+        let scale = SharedScale::logarithmic(10_f64, 1000.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 100.0);
+        scale.set_max(100_000.0);
+        assert_approx_eq!(scale.to_absolute(0.5), 1000.0);
+    }
+
+    #[test]
+    fn test_shared_across_threads() {
+        let scale = Arc::new(SharedScale::linear(0_f64, 100.0));
+
+        let writer = {
+            let scale = scale.clone();
+            thread::spawn(move || {
+                for i in 0..10_000 {
+                    if i % 2 == 0 {
+                        scale.set_bounds(1000.0, 2000.0);
+                    } else {
+                        scale.set_bounds(0.0, 100.0);
+                    }
+                }
+            })
+        };
+
+        for _ in 0..10_000 {
+            let value = scale.to_absolute(0.5);
+            assert!(value == 50.0 || value == 1500.0, "torn read: {}", value);
+        }
+
+        writer.join().unwrap();
+    }
+}
@@ -0,0 +1,331 @@
+use super::convert::*;
+use super::converter::SLICE_CHUNK;
+use super::*;
+use std::marker::PhantomData;
+
+// Distance to the target, relative to where the ramp started, that an exponential ramp has left after the ramp time.
+const EXPONENTIAL_RESIDUE: f64 = 0.001;
+// Exponential ramps snap to their target once they are closer than this in the relative domain.
+const EXPONENTIAL_EPSILON: f64 = 1e-6;
+
+/// How a [`SmoothedValue`] moves towards a new target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Smoothing {
+    /// Ramps linearly in the scale's relative domain, e.g. by equal intervals on a logarithmic frequency scale.
+    Relative,
+    /// Ramps linearly between the absolute values, regardless of the scale's shape.
+    Absolute,
+    /// Approaches the target in the relative domain like a one-pole lowpass, fast at first and slower towards the
+    /// end. After the ramp time 0.1 % of the distance is left, shortly after that the value snaps to the target.
+    Exponential,
+}
+
+/// A parameter value that ramps to new targets over a configurable time instead of jumping, which avoids the
+/// clicks ("zipper noise") that instant parameter changes cause in audio signals.
+///
+/// The value is stepped either per sample using [`SmoothedValue::next_sample`] and [`SmoothedValue::fill`], or per
+/// block using [`SmoothedValue::skip`]. Ramps are limited to the scale's range and a ramp time of zero makes changes
+/// take effect immediately.
+#[derive(Debug, Clone)]
+pub struct SmoothedValue<N, S> {
+    scale: S,
+    smoothing: Smoothing,
+    ramp_length: usize,
+    // relative values, except for absolute smoothing
+    current: f64,
+    target: f64,
+    step: f64,
+    remaining: usize,
+    coefficient: f64,
+    value_type: PhantomData<fn() -> N>,
+}
+
+impl<N, S> SmoothedValue<N, S>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    /// Creates a smoothed value that starts at `initial` and follows changes immediately until a ramp time is set.
+    pub fn new(scale: S, smoothing: Smoothing, initial: N) -> SmoothedValue<N, S> {
+        let mut smoothed = SmoothedValue {
+            scale,
+            smoothing,
+            ramp_length: 0,
+            current: 0.0,
+            target: 0.0,
+            step: 0.0,
+            remaining: 0,
+            coefficient: 0.0,
+            value_type: PhantomData,
+        };
+        smoothed.reset(initial);
+        smoothed
+    }
+
+    pub fn with_ramp_time(mut self, seconds: f64, sample_rate: f64) -> SmoothedValue<N, S> {
+        self.set_ramp_time(seconds, sample_rate);
+        self
+    }
+
+    /// Changes the ramp time, e.g. when the sample rate changes. A ramp in progress keeps its speed if it is linear.
+    pub fn set_ramp_time(&mut self, seconds: f64, sample_rate: f64) {
+        self.ramp_length = (seconds * sample_rate).round().max(0.0) as usize;
+        self.coefficient = if self.ramp_length == 0 {
+            0.0
+        } else {
+            EXPONENTIAL_RESIDUE.powf(1.0 / self.ramp_length as f64)
+        };
+    }
+
+    pub fn scale(&self) -> &S {
+        &self.scale
+    }
+
+    pub fn smoothing(&self) -> Smoothing {
+        self.smoothing
+    }
+
+    /// The ramp time in samples.
+    pub fn ramp_length(&self) -> usize {
+        self.ramp_length
+    }
+
+    /// Starts ramping from the current value to `target`.
+    pub fn set_target(&mut self, target: N) {
+        let target = self.domain_value(target);
+        self.start_ramp(target);
+    }
+
+    /// Starts ramping from the current value to the absolute value at the relative position `target`.
+    pub fn set_relative_target(&mut self, target: f64) {
+        let target = match self.smoothing {
+            Smoothing::Absolute => self.scale.to_clamped_absolute(target).to_float(),
+            Smoothing::Relative | Smoothing::Exponential => target.clamp(0.0, 1.0),
+        };
+        self.start_ramp(target);
+    }
+
+    /// Jumps to `value` without ramping, e.g. when playback starts.
+    pub fn reset(&mut self, value: N) {
+        self.target = self.domain_value(value);
+        self.current = self.target;
+        self.remaining = 0;
+    }
+
+    pub fn is_smoothing(&self) -> bool {
+        self.current != self.target
+    }
+
+    pub fn current(&self) -> N {
+        self.absolute(self.current)
+    }
+
+    pub fn target(&self) -> N {
+        self.absolute(self.target)
+    }
+
+    /// Advances by one sample and returns the new current value.
+    pub fn next_sample(&mut self) -> N {
+        self.skip(1)
+    }
+
+    /// Advances by a whole block of samples at once and returns the current value at its end, for parameters that
+    /// are only updated once per block.
+    pub fn skip(&mut self, samples: usize) -> N {
+        self.advance(samples);
+        self.current()
+    }
+
+    /// Advances by one sample per value of `values` and writes the current value after each of them into it.
+    pub fn fill(&mut self, values: &mut [N]) {
+        if !self.is_smoothing() {
+            let current = self.current();
+            values.fill(current);
+            return;
+        }
+
+        match self.smoothing {
+            Smoothing::Absolute => {
+                for value in values.iter_mut() {
+                    self.advance(1);
+                    *value = N::from_float(self.current);
+                }
+            }
+            Smoothing::Relative | Smoothing::Exponential => {
+                let mut rel = [0.0; SLICE_CHUNK];
+                for values in values.chunks_mut(SLICE_CHUNK) {
+                    let rel = &mut rel[..values.len()];
+                    for relative in rel.iter_mut() {
+                        self.advance(1);
+                        *relative = self.current;
+                    }
+                    self.scale.to_absolute_slice(rel, values);
+                }
+            }
+        }
+    }
+
+    fn start_ramp(&mut self, target: f64) {
+        self.target = target;
+        if self.ramp_length == 0 {
+            self.current = target;
+            self.remaining = 0;
+        } else {
+            self.step = (target - self.current) / self.ramp_length as f64;
+            self.remaining = self.ramp_length;
+        }
+    }
+
+    fn advance(&mut self, samples: usize) {
+        if !self.is_smoothing() {
+            return;
+        }
+
+        match self.smoothing {
+            Smoothing::Relative | Smoothing::Absolute => {
+                if samples >= self.remaining {
+                    self.current = self.target;
+                    self.remaining = 0;
+                } else {
+                    self.current += self.step * samples as f64;
+                    self.remaining -= samples;
+                }
+            }
+            Smoothing::Exponential => {
+                let residue = self.coefficient.powi(samples.min(i32::MAX as usize) as i32);
+                self.current = self.target + (self.current - self.target) * residue;
+                if (self.current - self.target).abs() < EXPONENTIAL_EPSILON {
+                    self.current = self.target;
+                }
+            }
+        }
+    }
+
+    // Maps an absolute value into the domain the ramp runs in, limited to the scale's range.
+    fn domain_value(&self, value: N) -> f64 {
+        match self.smoothing {
+            Smoothing::Absolute => {
+                let (min, max) = (self.scale.min(), self.scale.max());
+                if value > max {
+                    max.to_float()
+                } else if value < min {
+                    min.to_float()
+                } else {
+                    value.to_float()
+                }
+            }
+            Smoothing::Relative | Smoothing::Exponential => self.scale.to_clamped_relative(value),
+        }
+    }
+
+    fn absolute(&self, value: f64) -> N {
+        match self.smoothing {
+            Smoothing::Absolute => N::from_float(value),
+            Smoothing::Relative | Smoothing::Exponential => self.scale.to_absolute(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_relative_smoothing() {
+        let scale: LogarithmicScale<f64> = LogarithmicScale::new(10.0, 1000.0);
+        let mut smoothed =
+            SmoothedValue::new(scale, Smoothing::Relative, 10.0).with_ramp_time(0.001, 4000.0);
+        assert_eq!(smoothed.ramp_length(), 4);
+
+        smoothed.set_target(1000.0);
+        assert!(smoothed.is_smoothing());
+        assert_approx_eq!(smoothed.current(), 10.0);
+        assert_approx_eq!(smoothed.next_sample(), 31.6227766);
+        assert_approx_eq!(smoothed.next_sample(), 100.0);
+        assert_approx_eq!(smoothed.next_sample(), 316.227766);
+        assert_approx_eq!(smoothed.next_sample(), 1000.0);
+        assert!(!smoothed.is_smoothing());
+        assert_approx_eq!(smoothed.next_sample(), 1000.0);
+
+        smoothed.set_target(5000.0);
+        assert_approx_eq!(smoothed.target(), 1000.0);
+        assert!(!smoothed.is_smoothing());
+    }
+
+    #[test]
+    fn test_absolute_smoothing() {
+        let scale: LogarithmicScale<f64> = LogarithmicScale::new(10.0, 1000.0);
+        let mut smoothed =
+            SmoothedValue::new(scale, Smoothing::Absolute, 10.0).with_ramp_time(0.004, 1000.0);
+
+        smoothed.set_target(1000.0);
+        assert_approx_eq!(smoothed.next_sample(), 257.5);
+        assert_approx_eq!(smoothed.next_sample(), 505.0);
+
+        // a new target starts a new ramp from the current value
+        smoothed.set_relative_target(0.0);
+        assert_approx_eq!(smoothed.target(), 10.0);
+        assert_approx_eq!(smoothed.skip(2), 257.5);
+        assert_approx_eq!(smoothed.skip(10), 10.0);
+
+        smoothed.reset(100.0);
+        assert!(!smoothed.is_smoothing());
+        assert_approx_eq!(smoothed.current(), 100.0);
+    }
+
+    #[test]
+    fn test_exponential_smoothing() {
+        let scale: LinearScale<f64> = LinearScale::new(0.0, 1.0);
+        let mut smoothed =
+            SmoothedValue::new(scale, Smoothing::Exponential, 0.0).with_ramp_time(0.01, 48_000.0);
+
+        smoothed.set_target(1.0);
+        let first = smoothed.next_sample();
+        let second = smoothed.next_sample();
+        assert!(first > 0.0 && second - first < first);
+        assert_approx_eq!(smoothed.skip(478), 0.999, 1e-9);
+        assert!(smoothed.is_smoothing());
+        smoothed.skip(500);
+        assert!(!smoothed.is_smoothing());
+        assert_eq!(smoothed.current(), 1.0);
+    }
+
+    #[test]
+    fn test_fill() {
+        let scale: LogarithmicScale<f64> = LogarithmicScale::new(10.0, 1000.0);
+        let mut per_sample =
+            SmoothedValue::new(scale, Smoothing::Relative, 10.0).with_ramp_time(1.0, 300.0);
+        let mut per_block = per_sample.clone();
+        per_sample.set_target(1000.0);
+        per_block.set_target(1000.0);
+
+        let mut block = [0.0; 400];
+        per_sample.fill(&mut block);
+        for (i, value) in block.iter().enumerate() {
+            assert_approx_eq!(*value, per_block.next_sample());
+            assert_approx_eq!(
+                *value,
+                10f64.powf(1.0 + 2.0 * ((i + 1).min(300) as f64 / 300.0))
+            );
+        }
+    }
+
+    #[test]
+    fn test_instant_changes() {
+        let scale: LinearScale<i32> = LinearScale::new(0, 127);
+        let mut smoothed = SmoothedValue::new(scale, Smoothing::Exponential, 64);
+
+        smoothed.set_target(100);
+        assert!(!smoothed.is_smoothing());
+        assert_eq!(smoothed.current(), 100);
+        assert_eq!(smoothed.next_sample(), 100);
+
+        smoothed.set_ramp_time(0.5, 100.0);
+        smoothed.set_target(0);
+        assert!(smoothed.next_sample() < 100);
+    }
+}
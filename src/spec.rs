@@ -0,0 +1,756 @@
+use super::any::*;
+use super::broken::*;
+use super::circular::*;
+use super::convert::*;
+use super::decibel::*;
+use super::error::*;
+use super::linear::*;
+use super::logarithmic::*;
+use super::pitch::*;
+use super::power::*;
+use super::psychoacoustic::*;
+use super::spline::*;
+use super::stepped::*;
+use super::symlog::*;
+use super::table::*;
+use super::*;
+use std::fmt;
+use std::str::FromStr;
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn error<T>(&self, expected: &'static str) -> Result<T, ScaleError> {
+        Err(ScaleError::InvalidSyntax {
+            position: self.pos,
+            expected,
+        })
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char, expected: &'static str) -> Result<(), ScaleError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            self.error(expected)
+        }
+    }
+
+    fn at_section_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest().starts_with([';', ')'])
+    }
+
+    fn ident(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len());
+        self.pos += len;
+        Some(&rest[..len])
+    }
+
+    fn number(&mut self) -> Result<f64, ScaleError> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let unsigned = rest.trim_start_matches(['+', '-']);
+        let sign_len = rest.len() - unsigned.len();
+        let sign = match &rest[..sign_len] {
+            "" | "+" => 1.0,
+            "-" => -1.0,
+            _ => return self.error("a number"),
+        };
+
+        if unsigned.starts_with("inf") && !starts_alphanumeric(&unsigned[3..]) {
+            self.pos += sign_len + 3;
+            return Ok(sign * f64::INFINITY);
+        }
+
+        let mut len = unsigned
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(unsigned.len());
+        if len > 0 && unsigned[len..].starts_with(['e', 'E']) {
+            let exponent = &unsigned[len + 1..];
+            let exponent_sign = exponent.len() - exponent.trim_start_matches(['+', '-']).len();
+            let digits = exponent[exponent_sign..]
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(exponent.len() - exponent_sign);
+            if exponent_sign <= 1 && digits > 0 {
+                len += 1 + exponent_sign + digits;
+            }
+        }
+
+        let value: f64 = match unsigned[..len].parse() {
+            Ok(value) => value,
+            Err(_) => return self.error("a number"),
+        };
+
+        let suffix = &unsigned[len..];
+        let multiplier = match suffix.chars().next() {
+            Some('k') if !starts_alphanumeric(&suffix[1..]) => 1e3,
+            Some('M') if !starts_alphanumeric(&suffix[1..]) => 1e6,
+            _ => 1.0,
+        };
+        if multiplier != 1.0 {
+            len += 1;
+        }
+
+        self.pos += sign_len + len;
+        Ok(sign * value * multiplier)
+    }
+
+    // Values are rounded to the nearest value of `N`, like `Scale::parse_value` does.
+    fn value<N: RoundFromFloat>(&mut self) -> Result<N, ScaleError> {
+        N::nearest_from_float(self.number()?)
+    }
+
+    fn bounds<N: RoundFromFloat>(&mut self) -> Result<(N, N), ScaleError> {
+        let min = self.value()?;
+        self.expect(',', "','")?;
+        let max = self.value()?;
+        Ok((min, max))
+    }
+
+    fn breakpoint<N: RoundFromFloat>(&mut self) -> Result<(N, f64), ScaleError> {
+        let absolute = self.value()?;
+        self.expect('@', "'@'")?;
+        let relative = self.number()?;
+        Ok((absolute, relative))
+    }
+
+    fn curve(&mut self) -> Result<Curve, ScaleError> {
+        let position = self.pos;
+        match self.ident() {
+            Some("lin") => Ok(Curve::Linear),
+            Some("log") => Ok(Curve::Logarithmic),
+            Some("hold") => Ok(Curve::Hold),
+            Some("pow") => {
+                self.expect('(', "'('")?;
+                let exponent = self.number()?;
+                self.expect(')', "')'")?;
+                Ok(Curve::Power(exponent))
+            }
+            _ => {
+                self.pos = position;
+                self.error("a curve")
+            }
+        }
+    }
+
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, ScaleError>,
+    ) -> Result<Vec<T>, ScaleError> {
+        let mut items = Vec::new();
+        if self.at_section_end() {
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            if !self.eat(',') {
+                return Ok(items);
+            }
+        }
+    }
+
+    fn optional_list<T>(
+        &mut self,
+        item: impl FnMut(&mut Self) -> Result<T, ScaleError>,
+    ) -> Result<Vec<T>, ScaleError> {
+        if self.eat(';') {
+            self.list(item)
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    fn scale<N>(&mut self) -> Result<AnyScale<N>, ScaleError>
+    where
+        N: Sub<Output = N>
+            + Add<Output = N>
+            + PartialOrd
+            + FromFloat<f64>
+            + ToFloat<f64>
+            + RoundFromFloat
+            + Clone,
+    {
+        let mut modifiers = Vec::new();
+        let (name, name_position) = loop {
+            self.skip_whitespace();
+            let position = self.pos;
+            match self.ident() {
+                Some(modifier @ ("inv" | "approx" | "quant" | "power")) => {
+                    modifiers.push((modifier, position))
+                }
+                Some(name) => break (name, position),
+                None => return self.error("a scale type"),
+            }
+        };
+
+        let supported: &[&str] = match name {
+            "log" => &["inv", "approx"],
+            "lin" | "pow" | "symlog" | "circ" | "mel" | "bark" | "erb" => &["inv"],
+            "hz" | "midi" | "cents" => &["quant"],
+            "db" | "fader" => &["power"],
+            _ => &[],
+        };
+        for (modifier, position) in &modifiers {
+            if !supported.contains(modifier) {
+                self.pos = *position;
+                return self.error("a modifier supported by the scale type");
+            }
+        }
+        let has = |modifier| modifiers.iter().any(|(m, _)| *m == modifier);
+
+        self.expect('(', "'('")?;
+
+        let scale = match name {
+            "lin" => {
+                let (min, max) = self.bounds()?;
+                if has("inv") {
+                    LinearScale::try_inverted(min, max)?.into()
+                } else {
+                    LinearScale::try_new(min, max)?.into()
+                }
+            }
+            "log" => {
+                let (min, max) = self.bounds()?;
+                let scale = if has("inv") {
+                    LogarithmicScale::try_inverted(min, max)?
+                } else {
+                    LogarithmicScale::try_new(min, max)?
+                };
+                if has("approx") {
+                    scale.approximate().into()
+                } else {
+                    scale.into()
+                }
+            }
+            "pow" => {
+                let (min, max) = self.bounds()?;
+                self.expect(';', "';'")?;
+                let exponent = self.number()?;
+                if has("inv") {
+                    PowerScale::try_inverted(min, max, exponent)?.into()
+                } else {
+                    PowerScale::try_new(min, max, exponent)?.into()
+                }
+            }
+            "symlog" => {
+                let (min, max) = self.bounds()?;
+                self.expect(';', "';'")?;
+                let threshold = self.number()?;
+                if has("inv") {
+                    SymLogScale::try_inverted(min, max, threshold)?.into()
+                } else {
+                    SymLogScale::try_new(min, max, threshold)?.into()
+                }
+            }
+            "circ" => {
+                let (min, max) = self.bounds()?;
+                if has("inv") {
+                    CircularScale::try_inverted(min, max)?.into()
+                } else {
+                    CircularScale::try_new(min, max)?.into()
+                }
+            }
+            "mel" => {
+                let (min, max) = self.bounds()?;
+                if has("inv") {
+                    MelScale::try_inverted(min, max)?.into()
+                } else {
+                    MelScale::try_new(min, max)?.into()
+                }
+            }
+            "bark" => {
+                let (min, max) = self.bounds()?;
+                if has("inv") {
+                    BarkScale::try_inverted(min, max)?.into()
+                } else {
+                    BarkScale::try_new(min, max)?.into()
+                }
+            }
+            "erb" => {
+                let (min, max) = self.bounds()?;
+                if has("inv") {
+                    ErbScale::try_inverted(min, max)?.into()
+                } else {
+                    ErbScale::try_new(min, max)?.into()
+                }
+            }
+            "broken" => {
+                let (min, max) = self.bounds()?;
+                let steps = self.optional_list(Parser::breakpoint)?;
+                let curves = self.optional_list(Parser::curve)?;
+                if curves.is_empty() {
+                    BrokenScale::try_new(min, max, &steps)?.into()
+                } else {
+                    BrokenScale::try_with_curves(min, max, &steps, &curves)?.into()
+                }
+            }
+            "spline" => SplineScale::try_new(&self.list(Parser::breakpoint)?)?.into(),
+            "table" => TableScale::try_new(&self.list(Parser::breakpoint)?)?.into(),
+            "stepped" => SteppedScale::try_new(&self.list(Parser::value)?)?.into(),
+            "hz" | "midi" | "cents" => {
+                let unit = match name {
+                    "hz" => PitchUnit::Hz,
+                    "midi" => PitchUnit::MidiNote,
+                    _ => PitchUnit::Cents,
+                };
+                let (min, max) = self.bounds()?;
+                let scale = PitchScale::try_new(unit, min, max)?;
+                let scale = if self.eat(';') {
+                    let (note, hz) = self.breakpoint()?;
+                    scale.with_tuning(Tuning::try_with_reference(note, hz)?)
+                } else {
+                    scale
+                };
+                if has("quant") {
+                    scale.quantized().into()
+                } else {
+                    scale.into()
+                }
+            }
+            "db" => {
+                let (min_db, max_db) = self.bounds()?;
+                if has("power") {
+                    DecibelScale::try_power(min_db, max_db)?.into()
+                } else {
+                    DecibelScale::try_new(min_db, max_db)?.into()
+                }
+            }
+            "fader" => {
+                let (min_db, max_db) = self.bounds()?;
+                let steps = self.optional_list(Parser::breakpoint)?;
+                let curves = self.optional_list(Parser::curve)?;
+                let unit = if has("power") {
+                    DecibelUnit::Power
+                } else {
+                    DecibelUnit::Amplitude
+                };
+                FaderScale::try_from_parts(min_db, max_db, &steps, &curves, unit)?.into()
+            }
+            _ => {
+                self.pos = name_position;
+                return self.error("a scale type");
+            }
+        };
+
+        self.expect(')', "')'")?;
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            Ok(scale)
+        } else {
+            self.error("end of input")
+        }
+    }
+}
+
+fn starts_alphanumeric(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_alphanumeric())
+}
+
+/// Parses a scale from its textual notation.
+///
+/// A specification consists of optional modifiers, the type of scale and its arguments in parentheses. Arguments
+/// are grouped into sections separated by `;`, items within a section are separated by `,` and breakpoints are
+/// written as `absolute@relative`. Numbers may carry a `k` or `M` suffix and `inf` is spelled out.
+/// Absolute values are rounded to the nearest value of `N` and rejected if `N` can't represent them.
+///
+/// | Notation                                | Scale                                              |
+/// |-----------------------------------------|----------------------------------------------------|
+/// | `lin(0,100)`                            | [`LinearScale`]                                    |
+/// | `log(20,20k)`                           | [`LogarithmicScale`]                               |
+/// | `pow(0,100; 2)`                         | [`PowerScale`] with exponent `2`                   |
+/// | `symlog(-100,100; 1)`                   | [`SymLogScale`] with threshold `1`                 |
+/// | `circ(0,360)`                           | [`CircularScale`]                                  |
+/// | `mel(20,20k)`, `bark(..)`, `erb(..)`    | [`MelScale`], [`BarkScale`], [`ErbScale`]          |
+/// | `broken(-120,12; -60@0.25, -20@0.5)`    | [`BrokenScale`], optionally followed by `; curves` |
+/// | `spline(0@0, 10@0.5, 100@1)`            | [`SplineScale`]                                    |
+/// | `table(0@0, 10@0.5, 100@1)`             | [`TableScale`]                                     |
+/// | `stepped(1, 2, 4, 8)`                   | [`SteppedScale`]                                   |
+/// | `hz(20,20k)`, `midi(..)`, `cents(..)`   | [`PitchScale`], optionally followed by `; note@hz` |
+/// | `db(-60,+10)`                           | [`DecibelScale`]                                   |
+/// | `fader(-80,10; 0@0.75)`                 | [`FaderScale`], optionally followed by `; curves`  |
+///
+/// The curves of the segments of a broken scale or fader are `lin`, `log`, `pow(exponent)` and `hold`.
+/// The modifier `inv` inverts scales that support it, `approx` makes logarithmic scales use
+/// [approximate](LogarithmicScale::approximate) conversions, `quant` quantizes pitch scales and `power` makes decibel
+/// scales and faders refer to power ratios rather than amplitudes. Since decibel scales always start at silence,
+/// `-inf` is accepted as their lower bound and stands for a range starting at [`SILENCE_FLOOR_DB`].
+///
+/// Printing a scale yields its canonical notation, which parses back into an identical scale.
+impl<N> FromStr for AnyScale<N>
+where
+    N: Sub<Output = N>
+        + Add<Output = N>
+        + PartialOrd
+        + FromFloat<f64>
+        + ToFloat<f64>
+        + RoundFromFloat
+        + Clone,
+{
+    type Err = ScaleError;
+
+    fn from_str(spec: &str) -> Result<AnyScale<N>, ScaleError> {
+        Parser {
+            input: spec,
+            pos: 0,
+        }
+        .scale()
+    }
+}
+
+fn float<N: ToFloat<f64>>(value: N) -> f64 {
+    value.to_float()
+}
+
+fn write_modifier(f: &mut fmt::Formatter, modifier: &str, present: bool) -> fmt::Result {
+    if present {
+        write!(f, "{} ", modifier)
+    } else {
+        Ok(())
+    }
+}
+
+fn write_bounded<N, S>(f: &mut fmt::Formatter, name: &str, scale: &S, inverted: bool) -> fmt::Result
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N>,
+{
+    write_modifier(f, "inv", inverted)?;
+    write!(f, "{}({},{})", name, float(scale.min()), float(scale.max()))
+}
+
+fn write_breakpoints<N: ToFloat<f64>>(
+    f: &mut fmt::Formatter,
+    points: Vec<(N, f64)>,
+) -> fmt::Result {
+    for (index, (absolute, relative)) in points.into_iter().enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}@{}", float(absolute), relative)?;
+    }
+    Ok(())
+}
+
+fn write_segments<N: ToFloat<f64>>(
+    f: &mut fmt::Formatter,
+    steps: Vec<(N, f64)>,
+    curves: &[Curve],
+) -> fmt::Result {
+    let all_linear = curves.iter().all(|curve| curve == &Curve::Linear);
+    if !steps.is_empty() || !all_linear {
+        write!(f, "; ")?;
+        write_breakpoints(f, steps)?;
+    }
+    if !all_linear {
+        write!(f, "; ")?;
+        for (index, curve) in curves.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            match curve {
+                Curve::Linear => write!(f, "lin")?,
+                Curve::Logarithmic => write!(f, "log")?,
+                Curve::Power(exponent) => write!(f, "pow({})", exponent)?,
+                Curve::Hold => write!(f, "hold")?,
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<N> fmt::Display for AnyScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnyScale::Linear(scale) => write_bounded(f, "lin", scale, scale.is_inverted()),
+            AnyScale::Log(scale) => {
+                write_modifier(f, "approx", scale.is_approximate())?;
+                write_bounded(f, "log", scale, scale.is_inverted())
+            }
+            AnyScale::Circular(scale) => write_bounded(f, "circ", scale, scale.is_inverted()),
+            AnyScale::Mel(scale) => write_bounded(f, "mel", scale, scale.is_inverted()),
+            AnyScale::Bark(scale) => write_bounded(f, "bark", scale, scale.is_inverted()),
+            AnyScale::Erb(scale) => write_bounded(f, "erb", scale, scale.is_inverted()),
+            AnyScale::Power(scale) => {
+                write_modifier(f, "inv", scale.is_inverted())?;
+                write!(
+                    f,
+                    "pow({},{}; {})",
+                    float(scale.min()),
+                    float(scale.max()),
+                    scale.exponent()
+                )
+            }
+            AnyScale::SymLog(scale) => {
+                write_modifier(f, "inv", scale.is_inverted())?;
+                write!(
+                    f,
+                    "symlog({},{}; {})",
+                    float(scale.min()),
+                    float(scale.max()),
+                    scale.threshold()
+                )
+            }
+            AnyScale::Broken(scale) => {
+                write!(f, "broken({},{}", float(scale.min()), float(scale.max()))?;
+                write_segments(f, scale.steps(), scale.curves())?;
+                write!(f, ")")
+            }
+            AnyScale::Spline(scale) => {
+                write!(f, "spline(")?;
+                write_breakpoints(f, scale.points())?;
+                write!(f, ")")
+            }
+            AnyScale::Table(scale) => {
+                write!(f, "table(")?;
+                write_breakpoints(f, scale.samples())?;
+                write!(f, ")")
+            }
+            AnyScale::Stepped(scale) => {
+                write!(f, "stepped(")?;
+                for (index, value) in scale.values().iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", float(value.clone()))?;
+                }
+                write!(f, ")")
+            }
+            AnyScale::Pitch(scale) => {
+                let name = match scale.unit() {
+                    PitchUnit::Hz => "hz",
+                    PitchUnit::MidiNote => "midi",
+                    PitchUnit::Cents => "cents",
+                };
+                write_modifier(f, "quant", scale.is_quantized())?;
+                write!(f, "{}({},{}", name, float(scale.min()), float(scale.max()))?;
+                let tuning = scale.tuning();
+                if tuning != &Tuning::default() {
+                    write!(f, "; {}@{}", tuning.reference_note(), tuning.reference_hz())?;
+                }
+                write!(f, ")")
+            }
+            AnyScale::Decibel(scale) => {
+                write_modifier(f, "power", scale.unit() == DecibelUnit::Power)?;
+                write!(f, "db({},{})", scale.min_db(), scale.max_db())
+            }
+            AnyScale::Fader(scale) => {
+                write_modifier(f, "power", scale.unit() == DecibelUnit::Power)?;
+                write!(f, "fader({},{}", scale.min_db(), scale.max_db())?;
+                let delegate = scale.db_delegate();
+                write_segments(f, delegate.steps(), delegate.curves())?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    fn parse(spec: &str) -> AnyScale<f64> {
+        spec.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        assert_eq!(parse("lin(0,100)"), LinearScale::new(0.0, 100.0).into());
+        assert_eq!(
+            parse("log(20,20k)"),
+            LogarithmicScale::new(20.0, 20_000.0).into()
+        );
+        assert_eq!(
+            parse("inv log(10,10240)"),
+            LogarithmicScale::inverted(10.0, 10240.0).into()
+        );
+        assert_eq!(
+            parse("broken(-120,12; -60@0.25, -20@0.5)"),
+            BrokenScale::new(-120.0, 12.0, &[(-60.0, 0.25), (-20.0, 0.5)]).into()
+        );
+        assert_eq!(
+            parse("db(-inf,+10)"),
+            DecibelScale::new(f64::NEG_INFINITY, 10.0).into()
+        );
+        assert_eq!(
+            parse("  pow( 0 , 1.5k ;2e0 ) "),
+            PowerScale::new(0.0, 1500.0, 2.0).into()
+        );
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let scales: Vec<AnyScale<f64>> = vec![
+            LinearScale::new(0.0, 100.0).into(),
+            LogarithmicScale::inverted(10.0, 10240.0).into(),
+            LogarithmicScale::inverted(10.0, 10240.0)
+                .approximate()
+                .into(),
+            PowerScale::inverted(0.0, 1.0, 0.5).into(),
+            SymLogScale::new(-100.0, 100.0, 0.1).into(),
+            CircularScale::new(0.0, 360.0).into(),
+            MelScale::new(20.0, 20_000.0).into(),
+            BarkScale::inverted(20.0, 20_000.0).into(),
+            ErbScale::new(20.0, 20_000.0).into(),
+            BrokenScale::new(-120.0, 12.0, &[(-60.0, 0.25), (-20.0, 0.5)]).into(),
+            BrokenScale::with_curves(1.0, 100.0, &[], &[Curve::Logarithmic]).into(),
+            BrokenScale::with_curves(
+                0.0,
+                100.0,
+                &[(25.0, 0.5)],
+                &[Curve::Power(2.0), Curve::Hold],
+            )
+            .into(),
+            SplineScale::new(&[(0.0, 0.0), (10.0, 0.5), (100.0, 1.0)]).into(),
+            TableScale::new(&[(0.0, 0.0), (10.0, 0.5), (100.0, 1.0)]).into(),
+            SteppedScale::new(&[1.0, 2.0, 4.0, 8.0]).into(),
+            PitchScale::midi(0.0, 127.0)
+                .with_tuning(Tuning::new(432.0))
+                .quantized()
+                .into(),
+            PitchScale::hz(20.0, 20_000.0).into(),
+            DecibelScale::power(-60.0, 0.0).into(),
+            DecibelScale::new(f64::NEG_INFINITY, 10.0).into(),
+            FaderScale::console_fader().into(),
+            FaderScale::fader(f64::NEG_INFINITY, 10.0, &[(0.0, 0.75)]).into(),
+        ];
+
+        for scale in scales {
+            let spec = scale.to_string();
+            assert_eq!(parse(&spec), scale, "{}", spec);
+            assert_eq!(parse(&spec).to_string(), spec);
+        }
+    }
+
+    #[test]
+    fn test_canonical_notation() {
+        assert_eq!(parse("log(20, 20k)").to_string(), "log(20,20000)");
+        assert_eq!(
+            parse("broken(-120,12;-60@0.25,-20@0.5)").to_string(),
+            "broken(-120,12; -60@0.25, -20@0.5)"
+        );
+        assert_eq!(parse("quant midi(0,127)").to_string(), "quant midi(0,127)");
+        assert_eq!(parse("db(-inf,+10)").to_string(), "db(-inf,10)");
+        assert_eq!(parse("db(-120,+10)").to_string(), "db(-120,10)");
+        assert_eq!(
+            parse("fader(-inf,10; 0@0.75)").to_string(),
+            "fader(-inf,10; 0@0.75)"
+        );
+    }
+
+    #[test]
+    fn test_generic_number_type() {
+        let scale: AnyScale<i32> = "inv lin(0,127)".parse().unwrap();
+        assert_eq!(scale, LinearScale::inverted(0, 127).into());
+        assert_approx_eq!(scale.to_relative(127), 0.0);
+    }
+
+    #[test]
+    fn test_integral_values_are_rounded() {
+        let scale: AnyScale<i32> = "lin(0, 9.7)".parse().unwrap();
+        assert_eq!(scale, LinearScale::new(0, 10).into());
+        assert_eq!(
+            "stepped(1, 2.5, 3.5)".parse::<AnyScale<i32>>(),
+            Ok(SteppedScale::new(&[1, 2, 4]).into())
+        );
+        assert_eq!(
+            "lin(0, 300)".parse::<AnyScale<u8>>(),
+            Err(ScaleError::NotRepresentable { value: 300.0 })
+        );
+    }
+
+    #[test]
+    fn test_breakpoints_round_trip() {
+        for spec in ["broken(0,100; 29@0.5)", "fader(-80,10; -60.1@0.1, 2.9@0.9)"] {
+            let scale = parse(spec);
+            assert_eq!(scale.to_string(), spec);
+            assert_eq!(parse(&scale.to_string()), scale);
+        }
+
+        let integral: AnyScale<i32> = "broken(0,100; 29@0.5)".parse().unwrap();
+        assert_eq!(integral.to_string(), "broken(0,100; 29@0.5)");
+        assert_eq!(integral.to_string().parse::<AnyScale<i32>>(), Ok(integral));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        fn error(spec: &str) -> ScaleError {
+            spec.parse::<AnyScale<f64>>().unwrap_err()
+        }
+
+        assert_eq!(
+            error("linear(0,1)"),
+            ScaleError::InvalidSyntax {
+                position: 0,
+                expected: "a scale type"
+            }
+        );
+        assert_eq!(
+            error("lin(0;1)"),
+            ScaleError::InvalidSyntax {
+                position: 5,
+                expected: "','"
+            }
+        );
+        assert_eq!(
+            error("lin(0,x)"),
+            ScaleError::InvalidSyntax {
+                position: 6,
+                expected: "a number"
+            }
+        );
+        assert_eq!(
+            error("lin(0,1) log"),
+            ScaleError::InvalidSyntax {
+                position: 9,
+                expected: "end of input"
+            }
+        );
+        assert_eq!(
+            error("quant lin(0,1)"),
+            ScaleError::InvalidSyntax {
+                position: 0,
+                expected: "a modifier supported by the scale type"
+            }
+        );
+        assert_eq!(error("log(-1,10)"), ScaleError::NonPositiveLogBound);
+        assert_eq!(
+            error("broken(0,100; 50@0.5, 25@0.75)"),
+            ScaleError::NonMonotonicBreakpoints { index: 1 }
+        );
+        assert_eq!(error("hz(20,20k; 69@0)"), ScaleError::InvalidTuning);
+    }
+}
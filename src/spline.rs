@@ -0,0 +1,279 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// A scale that smoothly interpolates between arbitrary `(absolute, relative)` control points using a
+/// monotone cubic (Fritsch–Carlson) spline. Unlike a [`BrokenScale`](crate::prelude::BrokenScale) it has no
+/// kinks at the control points, yet it is guaranteed to be strictly increasing and therefore invertible.
+/// Beyond the first and last control point the curve is extrapolated linearly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplineScale<N> {
+    min: N,
+    max: N,
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    tangents: Vec<f64>,
+}
+
+impl<N> SplineScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Creates a spline through the given control points, which must be sorted by their absolute value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the control points are rejected by [`SplineScale::try_new`].
+    pub fn new(points: &[(N, f64)]) -> SplineScale<N> {
+        SplineScale::try_new(points)
+            .unwrap_or_else(|error| panic!("invalid spline control points: {}", error))
+    }
+
+    /// Like [`SplineScale::new`], but returns an error instead of panicking if the control points are fewer than
+    /// two, not finite or not strictly increasing in both their absolute and relative values.
+    pub fn try_new(points: &[(N, f64)]) -> Result<SplineScale<N>, ScaleError> {
+        let floats: Vec<(f64, f64)> = points
+            .iter()
+            .map(|(x, y)| (x.clone().to_float(), *y))
+            .collect();
+        check_points(&floats, 2)?;
+
+        let min = points[0].0.clone();
+        let max = points[points.len() - 1].0.clone();
+        let xs: Vec<f64> = points.iter().map(|(x, _)| x.clone().to_float()).collect();
+        let ys: Vec<f64> = points.iter().map(|(_, y)| *y).collect();
+        let tangents = monotone_tangents(&xs, &ys);
+
+        Ok(SplineScale {
+            min,
+            max,
+            xs,
+            ys,
+            tangents,
+        })
+    }
+
+    /// The control points of this spline as `(absolute, relative)` pairs.
+    pub fn points(&self) -> Vec<(N, f64)> {
+        self.xs
+            .iter()
+            .zip(&self.ys)
+            .map(|(x, y)| (N::from_float(*x), *y))
+            .collect()
+    }
+
+    fn hermite(&self, index: usize, t: f64) -> f64 {
+        let h = self.xs[index + 1] - self.xs[index];
+        let (y0, y1) = (self.ys[index], self.ys[index + 1]);
+        let (m0, m1) = (self.tangents[index], self.tangents[index + 1]);
+
+        let t2 = t * t;
+        let t3 = t2 * t;
+
+        let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+        let h10 = t3 - 2.0 * t2 + t;
+        let h01 = -2.0 * t3 + 3.0 * t2;
+        let h11 = t3 - t2;
+
+        h00 * y0 + h10 * h * m0 + h01 * y1 + h11 * h * m1
+    }
+
+    fn hermite_slope(&self, index: usize, t: f64) -> f64 {
+        let h = self.xs[index + 1] - self.xs[index];
+        let (y0, y1) = (self.ys[index], self.ys[index + 1]);
+        let (m0, m1) = (self.tangents[index], self.tangents[index + 1]);
+
+        let t2 = t * t;
+
+        let d00 = 6.0 * t2 - 6.0 * t;
+        let d10 = 3.0 * t2 - 4.0 * t + 1.0;
+        let d01 = -6.0 * t2 + 6.0 * t;
+        let d11 = 3.0 * t2 - 2.0 * t;
+
+        // derivative with respect to t
+        d00 * y0 + d10 * h * m0 + d01 * y1 + d11 * h * m1
+    }
+}
+
+impl<N> Scale<N> for SplineScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let x = absolute.to_float();
+        let last = self.xs.len() - 1;
+
+        if x <= self.xs[0] {
+            return self.ys[0] + self.tangents[0] * (x - self.xs[0]);
+        }
+        if x >= self.xs[last] {
+            return self.ys[last] + self.tangents[last] * (x - self.xs[last]);
+        }
+
+        let index = self.xs.partition_point(|xi| xi <= &x) - 1;
+        let t = (x - self.xs[index]) / (self.xs[index + 1] - self.xs[index]);
+        self.hermite(index, t)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let y = relative;
+        let last = self.ys.len() - 1;
+
+        if y <= self.ys[0] {
+            return N::from_float(self.xs[0] + (y - self.ys[0]) / self.tangents[0]);
+        }
+        if y >= self.ys[last] {
+            return N::from_float(self.xs[last] + (y - self.ys[last]) / self.tangents[last]);
+        }
+
+        let index = self.ys.partition_point(|yi| yi <= &y) - 1;
+
+        // the segment is monotone, so Newton's method safeguarded by bisection always converges
+
+        let mut lower = 0.0;
+        let mut upper = 1.0;
+        let mut t = (y - self.ys[index]) / (self.ys[index + 1] - self.ys[index]);
+
+        for _ in 0..64 {
+            let error = self.hermite(index, t) - y;
+            if error == 0.0 {
+                break;
+            }
+            if error < 0.0 {
+                lower = t;
+            } else {
+                upper = t;
+            }
+
+            let slope = self.hermite_slope(index, t);
+            let newton = t - error / slope;
+            t = if slope > 0.0 && newton > lower && newton < upper {
+                newton
+            } else {
+                (lower + upper) / 2.0
+            };
+
+            if upper - lower <= f64::EPSILON {
+                break;
+            }
+        }
+
+        let h = self.xs[index + 1] - self.xs[index];
+        N::from_float(self.xs[index] + t * h)
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+// Fritsch–Carlson: start with averaged secants and shrink them wherever they would cause overshoot
+fn monotone_tangents(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    let secants: Vec<f64> = (0..n - 1)
+        .map(|k| (ys[k + 1] - ys[k]) / (xs[k + 1] - xs[k]))
+        .collect();
+
+    let mut tangents = vec![0.0; n];
+    tangents[0] = secants[0];
+    tangents[n - 1] = secants[n - 2];
+    for k in 1..n - 1 {
+        tangents[k] = if secants[k - 1] * secants[k] > 0.0 {
+            (secants[k - 1] + secants[k]) / 2.0
+        } else {
+            0.0
+        };
+    }
+
+    for k in 0..n - 1 {
+        let a = tangents[k] / secants[k];
+        let b = tangents[k + 1] / secants[k];
+        let norm = a * a + b * b;
+        if norm > 9.0 {
+            let tau = 3.0 / norm.sqrt();
+            tangents[k] = tau * a * secants[k];
+            tangents[k + 1] = tau * b * secants[k];
+        }
+    }
+
+    tangents
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_spline_hits_control_points() {
+        let points = [(0.0, 0.0), (10.0, 0.5), (100.0, 0.8), (1000.0, 1.0)];
+        let scale: SplineScale<f64> = SplineScale::new(&points);
+
+        for (abs, rel) in points.iter() {
+            assert_approx_eq!(scale.to_relative(*abs), *rel);
+            assert_approx_eq!(scale.to_absolute(*rel), *abs);
+        }
+
+        assert_approx_eq!(scale.min(), 0.0);
+        assert_approx_eq!(scale.max(), 1000.0);
+    }
+
+    #[test]
+    fn test_spline_is_monotone() {
+        let scale: SplineScale<f64> =
+            SplineScale::new(&[(0.0, 0.0), (1.0, 0.45), (2.0, 0.5), (10.0, 1.0)]);
+
+        let mut previous = f64::NEG_INFINITY;
+        for i in 0..=1000 {
+            let relative = scale.to_relative(i as f64 * 0.01);
+            assert!(relative > previous);
+            previous = relative;
+        }
+    }
+
+    #[test]
+    fn test_spline_round_trip() {
+        let scale: SplineScale<f64> =
+            SplineScale::new(&[(-10.0, 0.0), (0.0, 0.2), (5.0, 0.7), (10.0, 1.0)]);
+
+        for i in -5..=25 {
+            let relative = i as f64 * 0.05;
+            assert_approx_eq!(scale.to_relative(scale.to_absolute(relative)), relative);
+        }
+    }
+
+    #[test]
+    fn test_spline_of_line_is_linear() {
+        let scale: SplineScale<f64> = SplineScale::new(&[(0.0, 0.0), (50.0, 0.5), (100.0, 1.0)]);
+        assert_approx_eq!(scale.to_relative(25.0), 0.25);
+        assert_approx_eq!(scale.to_absolute(0.9), 90.0);
+        assert_approx_eq!(scale.to_absolute(1.5), 150.0);
+    }
+
+    #[test]
+    fn test_spline_try_new() {
+        assert!(SplineScale::try_new(&[(0.0, 0.0), (1.0, 1.0)]).is_ok());
+        assert_eq!(
+            SplineScale::try_new(&[(0.0, 0.0)]),
+            Err(ScaleError::NotEnoughPoints {
+                required: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            SplineScale::try_new(&[(0.0, 0.0), (1.0, 0.5), (0.5, 1.0)]),
+            Err(ScaleError::NonMonotonicBreakpoints { index: 2 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid spline control points")]
+    fn test_spline_new_rejects_unsorted_points() {
+        SplineScale::new(&[(0.0, 0.0), (1.0, 0.5), (0.5, 1.0)]);
+    }
+}
@@ -0,0 +1,297 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// A scale with a fixed number of discrete values, e.g. for selector knobs. The relative range is divided into
+/// equally wide buckets, one per step. [`to_absolute`](Scale::to_absolute) yields the value of the bucket a relative
+/// value falls into, [`to_relative`](Scale::to_relative) yields the centre of the bucket of the step nearest to an
+/// absolute value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SteppedScale<N> {
+    values: Vec<N>,
+}
+
+impl<N> SteppedScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Creates a scale from a list of values sorted in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the values are rejected by [`SteppedScale::try_new`].
+    pub fn new(values: &[N]) -> SteppedScale<N> {
+        SteppedScale::try_new(values).unwrap_or_else(|error| panic!("invalid steps: {}", error))
+    }
+
+    /// Creates a scale of `count` evenly spaced values from `min` to `max`, each rounded to the nearest value of `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the values are rejected by [`SteppedScale::try_linear`].
+    pub fn linear(min: N, max: N, count: usize) -> SteppedScale<N>
+    where
+        N: RoundFromFloat,
+    {
+        SteppedScale::try_linear(min, max, count)
+            .unwrap_or_else(|error| panic!("invalid linear steps: {}", error))
+    }
+
+    /// Like [`SteppedScale::linear`], but returns an error instead of panicking if `count` is zero or the rounded
+    /// values are not strictly increasing, e.g. because an integral range holds fewer than `count` distinct values.
+    pub fn try_linear(min: N, max: N, count: usize) -> Result<SteppedScale<N>, ScaleError>
+    where
+        N: RoundFromFloat,
+    {
+        let min = min.to_float();
+        let max = max.to_float();
+        let gaps = count.saturating_sub(1).max(1) as f64;
+        let values = (0..count)
+            .map(|i| N::nearest_from_float(min + (max - min) * i as f64 / gaps))
+            .collect::<Result<Vec<N>, ScaleError>>()?;
+        SteppedScale::try_new(&values)
+    }
+
+    /// Like [`SteppedScale::new`], but rejects empty lists as well as values that are not finite or not strictly increasing.
+    pub fn try_new(values: &[N]) -> Result<SteppedScale<N>, ScaleError> {
+        let floats: Vec<(f64, f64)> = values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.clone().to_float(), i as f64))
+            .collect();
+        check_points(&floats, 1)?;
+        Ok(SteppedScale {
+            values: values.to_vec(),
+        })
+    }
+
+    pub fn values(&self) -> &[N] {
+        &self.values
+    }
+
+    /// The index of the step a relative value falls into.
+    pub fn step_index(&self, relative: f64) -> usize {
+        step_index(relative, self.values.len())
+    }
+
+    fn nearest_index(&self, absolute: f64) -> usize {
+        let mut nearest = 0;
+        let mut distance = f64::INFINITY;
+        for (i, value) in self.values.iter().enumerate() {
+            let d = (value.clone().to_float() - absolute).abs();
+            if d < distance {
+                nearest = i;
+                distance = d;
+            }
+        }
+        nearest
+    }
+}
+
+impl<N> Scale<N> for SteppedScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let index = self.nearest_index(absolute.to_float());
+        step_centre(index, self.values.len())
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        self.values[self.step_index(relative)].clone()
+    }
+
+    fn max(&self) -> N {
+        self.values[self.values.len() - 1].clone()
+    }
+
+    fn min(&self) -> N {
+        self.values[0].clone()
+    }
+}
+
+/// The enum-backed counterpart of a [`SteppedScale`] that maps relative values to arbitrary options,
+/// such as the variants of a filter type or waveform enum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumScale<T> {
+    options: Vec<T>,
+}
+
+impl<T> EnumScale<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a scale from a list of options in the order in which they should be laid out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no options are provided.
+    pub fn new(options: &[T]) -> EnumScale<T> {
+        assert!(
+            !options.is_empty(),
+            "an enum scale needs at least one option"
+        );
+        EnumScale {
+            options: options.to_vec(),
+        }
+    }
+
+    /// Like [`EnumScale::new`], but rejects empty lists.
+    pub fn try_new(options: &[T]) -> Result<EnumScale<T>, ScaleError> {
+        if options.is_empty() {
+            Err(ScaleError::NotEnoughPoints {
+                required: 1,
+                actual: 0,
+            })
+        } else {
+            Ok(EnumScale::new(options))
+        }
+    }
+
+    pub fn options(&self) -> &[T] {
+        &self.options
+    }
+
+    /// The centre of the bucket of the given option or `None` if it is not part of this scale.
+    pub fn to_relative(&self, option: &T) -> Option<f64> {
+        self.options
+            .iter()
+            .position(|o| o == option)
+            .map(|index| step_centre(index, self.options.len()))
+    }
+
+    /// The option whose bucket the given relative value falls into.
+    pub fn to_absolute(&self, relative: f64) -> T {
+        self.options[step_index(relative, self.options.len())].clone()
+    }
+}
+
+fn step_index(relative: f64, count: usize) -> usize {
+    let index = (relative * count as f64).floor();
+    if index <= 0.0 {
+        0
+    } else {
+        (index as usize).min(count - 1)
+    }
+}
+
+fn step_centre(index: usize, count: usize) -> f64 {
+    (index as f64 + 0.5) / count as f64
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_stepped_scale() {
+        let scale: SteppedScale<usize> = SteppedScale::new(&[1, 2, 4, 8]);
+
+        assert_eq!(scale.to_absolute(-0.5), 1);
+        assert_eq!(scale.to_absolute(0.0), 1);
+        assert_eq!(scale.to_absolute(0.24), 1);
+        assert_eq!(scale.to_absolute(0.25), 2);
+        assert_eq!(scale.to_absolute(0.6), 4);
+        assert_eq!(scale.to_absolute(0.999), 8);
+        assert_eq!(scale.to_absolute(1.0), 8);
+        assert_eq!(scale.to_absolute(1.5), 8);
+
+        assert_approx_eq!(scale.to_relative(1), 0.125);
+        assert_approx_eq!(scale.to_relative(2), 0.375);
+        assert_approx_eq!(scale.to_relative(5), 0.625);
+        assert_approx_eq!(scale.to_relative(7), 0.875);
+
+        assert_eq!(scale.min(), 1);
+        assert_eq!(scale.max(), 8);
+    }
+
+    #[test]
+    fn test_stepped_linear() {
+        let scale: SteppedScale<f64> = SteppedScale::linear(0.0, 1.0, 5);
+        assert_eq!(scale.values(), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_approx_eq!(scale.to_absolute(0.5), 0.5);
+        assert_eq!(scale.step_index(0.39), 1);
+    }
+
+    #[test]
+    fn test_stepped_linear_integral() {
+        let scale: SteppedScale<usize> = SteppedScale::linear(0, 10, 4);
+        assert_eq!(scale.values(), &[0, 3, 7, 10]);
+
+        let scale: SteppedScale<u8> = SteppedScale::linear(0, 2, 3);
+        assert_eq!(scale.values(), &[0, 1, 2]);
+
+        assert_eq!(
+            SteppedScale::<usize>::try_linear(0, 2, 4),
+            Err(ScaleError::NonMonotonicBreakpoints { index: 2 })
+        );
+        assert_eq!(
+            SteppedScale::<usize>::try_linear(0, 2, 0),
+            Err(ScaleError::NotEnoughPoints {
+                required: 1,
+                actual: 0
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid linear steps")]
+    fn test_stepped_linear_too_many_steps() {
+        SteppedScale::<usize>::linear(0, 2, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid steps")]
+    fn test_stepped_new_rejects_unsorted_values() {
+        SteppedScale::new(&[1, 4, 2, 8]);
+    }
+
+    #[test]
+    fn test_stepped_round_trip() {
+        let scale: SteppedScale<i32> = SteppedScale::linear(-3, 3, 7);
+        for value in -3..=3 {
+            assert_eq!(scale.to_absolute(scale.to_relative(value)), value);
+        }
+    }
+
+    #[test]
+    fn test_enum_scale() {
+        #[derive(Debug, Clone, PartialEq)]
+        enum Waveform {
+            Sine,
+            Triangle,
+            Square,
+        }
+
+        let scale = EnumScale::new(&[Waveform::Sine, Waveform::Triangle, Waveform::Square]);
+
+        assert_eq!(scale.to_absolute(0.0), Waveform::Sine);
+        assert_eq!(scale.to_absolute(0.5), Waveform::Triangle);
+        assert_eq!(scale.to_absolute(1.0), Waveform::Square);
+
+        assert_approx_eq!(scale.to_relative(&Waveform::Sine).unwrap(), 1.0 / 6.0);
+        assert_approx_eq!(scale.to_relative(&Waveform::Square).unwrap(), 5.0 / 6.0);
+
+        let partial = EnumScale::new(&[Waveform::Sine]);
+        assert_eq!(partial.to_relative(&Waveform::Square), None);
+    }
+
+    #[test]
+    fn test_stepped_try_new() {
+        assert!(SteppedScale::try_new(&[1, 2, 3]).is_ok());
+        assert_eq!(
+            SteppedScale::<f64>::try_new(&[]),
+            Err(ScaleError::NotEnoughPoints {
+                required: 1,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            SteppedScale::try_new(&[1, 3, 2]),
+            Err(ScaleError::NonMonotonicBreakpoints { index: 2 })
+        );
+        assert!(EnumScale::<u8>::try_new(&[]).is_err());
+    }
+}
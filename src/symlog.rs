@@ -0,0 +1,182 @@
+use super::convert::*;
+use super::error::*;
+use super::linear::*;
+use super::*;
+
+/// A symmetric logarithmic scale that behaves linearly within `-threshold..threshold` and logarithmically beyond
+/// that on both sides of zero. Unlike a [`LogarithmicScale`](crate::prelude::LogarithmicScale) it can therefore span
+/// zero and negative values, which makes it suitable for bipolar parameters like offsets, detune or pitch bend.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymLogScale<N> {
+    min: N,
+    max: N,
+    threshold: f64,
+    linear_delegate: LinearScale<f64>,
+}
+
+impl<N> SymLogScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    pub fn new(min: N, max: N, threshold: f64) -> SymLogScale<N> {
+        SymLogScale {
+            linear_delegate: LinearScale::new(
+                symlog(min.clone().to_float(), threshold),
+                symlog(max.clone().to_float(), threshold),
+            ),
+            min,
+            max,
+            threshold,
+        }
+    }
+
+    pub fn inverted(min: N, max: N, threshold: f64) -> SymLogScale<N> {
+        SymLogScale {
+            linear_delegate: LinearScale::inverted(
+                symlog(min.clone().to_float(), threshold),
+                symlog(max.clone().to_float(), threshold),
+            ),
+            min,
+            max,
+            threshold,
+        }
+    }
+
+    /// Like [`SymLogScale::new`], but rejects empty or non-finite ranges and thresholds that are not greater than zero.
+    pub fn try_new(min: N, max: N, threshold: f64) -> Result<SymLogScale<N>, ScaleError> {
+        check_range(min.clone().to_float(), max.clone().to_float())?;
+        check_threshold(threshold)?;
+        Ok(SymLogScale::new(min, max, threshold))
+    }
+
+    /// Like [`SymLogScale::inverted`], but rejects empty or non-finite ranges and thresholds that are not greater than zero.
+    pub fn try_inverted(min: N, max: N, threshold: f64) -> Result<SymLogScale<N>, ScaleError> {
+        check_range(min.clone().to_float(), max.clone().to_float())?;
+        check_threshold(threshold)?;
+        Ok(SymLogScale::inverted(min, max, threshold))
+    }
+
+    pub fn is_inverted(&self) -> bool {
+        self.linear_delegate.is_inverted()
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+}
+
+impl<N> Scale<N> for SymLogScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        let transformed = symlog(absolute.to_float(), self.threshold);
+        self.linear_delegate.to_relative(transformed)
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        let transformed = self.linear_delegate.to_absolute(relative);
+        N::from_float(symexp(transformed, self.threshold))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+// x / c within -c..c and sign(x) * (1 + log10(|x| / c)) beyond, which meet with the same value at ±c
+
+fn symlog(x: f64, threshold: f64) -> f64 {
+    let scaled = x / threshold;
+    if scaled.abs() <= 1.0 {
+        scaled
+    } else {
+        scaled.signum() * (1.0 + scaled.abs().log10())
+    }
+}
+
+fn symexp(y: f64, threshold: f64) -> f64 {
+    if y.abs() <= 1.0 {
+        y * threshold
+    } else {
+        y.signum() * threshold * 10f64.powf(y.abs() - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_symlog() {
+        let scale: SymLogScale<f64> = SymLogScale::new(-1000.0, 1000.0, 1.0);
+
+        assert_approx_eq!(scale.to_relative(0.0), 0.5);
+        assert_approx_eq!(scale.to_relative(0.5), 0.5625);
+        assert_approx_eq!(scale.to_relative(1.0), 0.625);
+        assert_approx_eq!(scale.to_relative(10.0), 0.75);
+        assert_approx_eq!(scale.to_relative(-10.0), 0.25);
+        assert_approx_eq!(scale.to_relative(1000.0), 1.0);
+        assert_approx_eq!(scale.to_relative(-1000.0), 0.0);
+
+        assert_approx_eq!(scale.to_absolute(0.5), 0.0);
+        assert_approx_eq!(scale.to_absolute(0.5625), 0.5);
+        assert_approx_eq!(scale.to_absolute(0.625), 1.0);
+        assert_approx_eq!(scale.to_absolute(0.25), -10.0);
+        assert_approx_eq!(scale.to_absolute(1.0), 1000.0);
+    }
+
+    #[test]
+    fn test_symlog_is_linear_within_threshold() {
+        let scale: SymLogScale<f64> = SymLogScale::new(-1000.0, 1000.0, 100.0);
+
+        let unit = scale.to_relative(1.0) - 0.5;
+        for i in -100..=100 {
+            let absolute = i as f64;
+            assert_approx_eq!(scale.to_relative(absolute) - 0.5, unit * absolute);
+            assert_approx_eq!(scale.to_absolute(0.5 + unit * absolute), absolute);
+        }
+
+        // continuous at the threshold on both sides
+        for threshold in [-100.0, 100.0] {
+            let below = scale.to_relative(threshold * (1.0 - 1e-9));
+            let above = scale.to_relative(threshold * (1.0 + 1e-9));
+            assert_approx_eq!(below, above, 1e-9);
+        }
+        assert_approx_eq!(
+            scale.to_relative(1000.0) - 0.5,
+            2.0 * (scale.to_relative(100.0) - 0.5)
+        );
+    }
+
+    #[test]
+    fn test_symlog_asymmetric_inverted() {
+        let scale: SymLogScale<f64> = SymLogScale::inverted(-10.0, 1000.0, 0.5);
+
+        assert_approx_eq!(scale.to_absolute(0.0), 1000.0);
+        assert_approx_eq!(scale.to_absolute(1.0), -10.0);
+        for i in -2..=12 {
+            let relative = i as f64 * 0.1;
+            assert_approx_eq!(scale.to_relative(scale.to_absolute(relative)), relative);
+        }
+    }
+
+    #[test]
+    fn test_symlog_try_new() {
+        assert!(SymLogScale::try_new(-100.0, 100.0, 1.0).is_ok());
+        assert_eq!(
+            SymLogScale::try_new(-100.0, 100.0, 0.0),
+            Err(ScaleError::InvalidThreshold)
+        );
+        assert_eq!(
+            SymLogScale::try_inverted(100.0, 100.0, 1.0),
+            Err(ScaleError::EmptyRange)
+        );
+    }
+}
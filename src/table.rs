@@ -0,0 +1,159 @@
+use super::convert::*;
+use super::error::*;
+use super::*;
+
+/// A scale defined by a table of measured `(absolute, relative)` samples, e.g. taken from a sweep of a hardware
+/// potentiometer or ADC. Values between samples are interpolated linearly, values beyond the first or last sample
+/// are extrapolated from the outermost segments. Lookups in both directions use binary search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableScale<N> {
+    min: N,
+    max: N,
+    absolutes: Vec<f64>,
+    relatives: Vec<f64>,
+}
+
+impl<N> TableScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    /// Creates a table scale from samples sorted by their absolute value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the samples are rejected by [`TableScale::try_new`].
+    pub fn new(samples: &[(N, f64)]) -> TableScale<N> {
+        TableScale::try_new(samples)
+            .unwrap_or_else(|error| panic!("invalid table samples: {}", error))
+    }
+
+    /// Like [`TableScale::new`], but returns an error instead of panicking if the samples are fewer than two,
+    /// not finite or not strictly increasing in both their absolute and relative values.
+    pub fn try_new(samples: &[(N, f64)]) -> Result<TableScale<N>, ScaleError> {
+        let floats: Vec<(f64, f64)> = samples
+            .iter()
+            .map(|(a, r)| (a.clone().to_float(), *r))
+            .collect();
+        check_points(&floats, 2)?;
+
+        Ok(TableScale {
+            min: samples[0].0.clone(),
+            max: samples[samples.len() - 1].0.clone(),
+            absolutes: floats.iter().map(|(a, _)| *a).collect(),
+            relatives: floats.iter().map(|(_, r)| *r).collect(),
+        })
+    }
+
+    /// The samples of this table as `(absolute, relative)` pairs.
+    pub fn samples(&self) -> Vec<(N, f64)> {
+        self.absolutes
+            .iter()
+            .zip(&self.relatives)
+            .map(|(a, r)| (N::from_float(*a), *r))
+            .collect()
+    }
+}
+
+impl<N> Scale<N> for TableScale<N>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+{
+    fn to_relative(&self, absolute: N) -> f64 {
+        interpolate(&self.absolutes, &self.relatives, absolute.to_float())
+    }
+
+    fn to_absolute(&self, relative: f64) -> N {
+        N::from_float(interpolate(&self.relatives, &self.absolutes, relative))
+    }
+
+    fn max(&self) -> N {
+        self.max.clone()
+    }
+
+    fn min(&self) -> N {
+        self.min.clone()
+    }
+}
+
+fn interpolate(from: &[f64], to: &[f64], value: f64) -> f64 {
+    // index of the segment containing the value, clamped to the outermost segments for extrapolation
+    let index = from
+        .partition_point(|f| f <= &value)
+        .saturating_sub(1)
+        .min(from.len() - 2);
+
+    let (x0, x1) = (from[index], from[index + 1]);
+    let (y0, y1) = (to[index], to[index + 1]);
+
+    y0 + (value - x0) * (y1 - y0) / (x1 - x0)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn test_table_lookup() {
+        let table: TableScale<f64> =
+            TableScale::new(&[(0.0, 0.0), (100.0, 0.1), (500.0, 0.5), (1000.0, 1.0)]);
+
+        assert_approx_eq!(table.to_relative(0.0), 0.0);
+        assert_approx_eq!(table.to_relative(50.0), 0.05);
+        assert_approx_eq!(table.to_relative(100.0), 0.1);
+        assert_approx_eq!(table.to_relative(300.0), 0.3);
+        assert_approx_eq!(table.to_relative(750.0), 0.75);
+        assert_approx_eq!(table.to_relative(1000.0), 1.0);
+
+        assert_approx_eq!(table.to_absolute(0.0), 0.0);
+        assert_approx_eq!(table.to_absolute(0.05), 50.0);
+        assert_approx_eq!(table.to_absolute(0.3), 300.0);
+        assert_approx_eq!(table.to_absolute(0.75), 750.0);
+        assert_approx_eq!(table.to_absolute(1.0), 1000.0);
+    }
+
+    #[test]
+    fn test_table_extrapolation() {
+        let table: TableScale<f64> = TableScale::new(&[(10.0, 0.0), (20.0, 0.5), (40.0, 1.0)]);
+
+        assert_approx_eq!(table.to_relative(0.0), -0.5);
+        assert_approx_eq!(table.to_relative(80.0), 2.0);
+        assert_approx_eq!(table.to_absolute(-0.5), 0.0);
+        assert_approx_eq!(table.to_absolute(2.0), 80.0);
+
+        assert_approx_eq!(table.to_clamped_relative(0.0), 0.0);
+        assert_approx_eq!(table.to_clamped_absolute(2.0), 40.0);
+    }
+
+    #[test]
+    fn test_table_integral() {
+        let adc: TableScale<u16> = TableScale::new(&[(0, 0.0), (1024, 0.2), (4095, 1.0)]);
+        let db = LinearScale::new(-60.0, 0.0);
+
+        assert_approx_eq!((&adc, &db).convert(1024), -48f64);
+        assert_eq!((&adc, &db).convert_back(-48.0), 1024);
+    }
+
+    #[test]
+    fn test_table_try_new() {
+        assert!(TableScale::try_new(&[(0.0, 0.0), (1.0, 1.0)]).is_ok());
+        assert_eq!(
+            TableScale::<f64>::try_new(&[]),
+            Err(ScaleError::NotEnoughPoints {
+                required: 2,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            TableScale::try_new(&[(0.0, 0.0), (1.0, 1.0), (1.0, 1.5)]),
+            Err(ScaleError::NonMonotonicBreakpoints { index: 2 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid table samples")]
+    fn test_table_new_rejects_unsorted_samples() {
+        TableScale::new(&[(0.0, 0.0), (10.0, 0.5), (5.0, 1.0)]);
+    }
+}
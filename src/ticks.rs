@@ -0,0 +1,267 @@
+use super::convert::*;
+use super::*;
+
+/// A tick mark of a scale, e.g. on the axis of a chart or next to a meter or fader.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick<N> {
+    pub value: N,
+    pub relative: f64,
+    /// Major ticks are usually drawn longer and labelled, minor ticks subdivide the space between them.
+    pub major: bool,
+}
+
+/// Tick values with 1-2-5 steps between `from` and `to`, aiming for `count` major ticks. Each major step is
+/// subdivided into four or five minor steps.
+pub(crate) fn nice_values(from: f64, to: f64, count: usize) -> Vec<(f64, bool)> {
+    let (lo, hi) = if from <= to { (from, to) } else { (to, from) };
+    if count == 0 || !lo.is_finite() || !hi.is_finite() || lo == hi {
+        return Vec::new();
+    }
+
+    let raw_step = (hi - lo) / count as f64;
+    let exponent = raw_step.log10().floor() as i32;
+    let mantissa = raw_step / pow10(1.0, exponent);
+    let (step, subdivisions) = if mantissa <= 1.0 {
+        (10, 5)
+    } else if mantissa <= 2.0 {
+        (20, 4)
+    } else if mantissa <= 5.0 {
+        (50, 5)
+    } else {
+        (100, 5)
+    };
+
+    // all values are integral multiples of 10^(exponent - 1), which keeps them exact for decimal steps
+    let minor = step / subdivisions;
+    let unit = pow10(1.0, exponent - 1);
+    let tolerance = 1e-9 * (hi - lo) / unit;
+    let first = ((lo / unit - tolerance) / minor as f64).ceil() as i64;
+    let last = ((hi / unit + tolerance) / minor as f64).floor() as i64;
+
+    (first..=last)
+        .map(|i| {
+            (
+                pow10((i * minor) as f64, exponent - 1),
+                i % subdivisions == 0,
+            )
+        })
+        .collect()
+}
+
+/// Tick values at decades and sub-decades between `from` and `to`, aiming for `count` major ticks. Falls back to
+/// [`nice_values`] for ranges that are too narrow or not strictly positive.
+pub(crate) fn decade_values(from: f64, to: f64, count: usize) -> Vec<(f64, bool)> {
+    let (lo, hi) = if from <= to { (from, to) } else { (to, from) };
+    if count == 0 || lo <= 0.0 || !hi.is_finite() || lo == hi {
+        return nice_values(lo, hi, count);
+    }
+
+    let decades = (hi / lo).log10();
+    let count = count as f64;
+    if decades * 3.0 < count / 2.0 {
+        return nice_values(lo, hi, count as usize);
+    }
+
+    // majors at every `stride`th decade, or at 1, 2 and 5 within each decade if there are only a few decades
+    let stride = (decades / count).ceil().max(1.0) as i32;
+    let sub_decades = stride == 1 && (decades * 3.0 - count).abs() < (decades - count).abs();
+
+    let first = lo.log10().floor() as i32;
+    let last = hi.log10().ceil() as i32;
+    let mut values = Vec::new();
+    for decade in first..=last {
+        for mantissa in 1..=9 {
+            let major = if stride > 1 {
+                if mantissa > 1 {
+                    break;
+                }
+                decade.rem_euclid(stride) == 0
+            } else if sub_decades {
+                mantissa == 1 || mantissa == 2 || mantissa == 5
+            } else {
+                mantissa == 1
+            };
+            let value = pow10(mantissa as f64, decade);
+            if value >= lo * (1.0 - 1e-9) && value <= hi * (1.0 + 1e-9) {
+                values.push((value, major));
+            }
+        }
+    }
+    values
+}
+
+/// Converts tick values to the scale's number type and pairs them with their relative positions, merging
+/// ticks that end up at the same position. The result is ordered by relative position.
+pub(crate) fn to_ticks<N, S>(scale: &S, values: Vec<(f64, bool)>) -> Vec<Tick<N>>
+where
+    N: Sub<Output = N> + Add<Output = N> + PartialOrd + FromFloat<f64> + ToFloat<f64> + Clone,
+    S: Scale<N> + ?Sized,
+{
+    let mut ticks: Vec<Tick<N>> = values
+        .into_iter()
+        .map(|(value, major)| {
+            let value = N::from_float(value);
+            Tick {
+                relative: scale.to_relative(value.clone()),
+                value,
+                major,
+            }
+        })
+        .filter(|tick| tick.relative.is_finite())
+        .collect();
+    merge_ticks(&mut ticks);
+    ticks
+}
+
+pub(crate) fn merge_ticks<N>(ticks: &mut Vec<Tick<N>>) {
+    // relative values of ticks are always finite
+    ticks.sort_by(|a, b| {
+        a.relative
+            .partial_cmp(&b.relative)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    ticks.dedup_by(|next, kept| {
+        let duplicate = (next.relative - kept.relative).abs() < 1e-9;
+        if duplicate {
+            kept.major |= next.major;
+        }
+        duplicate
+    });
+}
+
+pub(crate) fn pow10(mantissa: f64, exponent: i32) -> f64 {
+    // dividing by an exact power of ten rounds correctly, multiplying by an inexact negative one does not
+    if exponent >= 0 {
+        mantissa * 10f64.powi(exponent)
+    } else {
+        mantissa / 10f64.powi(-exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::prelude::*;
+    use assert_approx_eq::*;
+
+    fn majors<N: Clone>(ticks: &[Tick<N>]) -> Vec<N> {
+        ticks
+            .iter()
+            .filter(|tick| tick.major)
+            .map(|tick| tick.value.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_nice_values() {
+        let values = nice_values(0.0, 100.0, 5);
+        let majors: Vec<f64> = values.iter().filter(|(_, m)| *m).map(|(v, _)| *v).collect();
+        assert_eq!(majors, vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+        assert_eq!(values.len(), 21);
+
+        let majors: Vec<f64> = nice_values(-0.35, 0.35, 7)
+            .into_iter()
+            .filter(|(_, m)| *m)
+            .map(|(v, _)| v)
+            .collect();
+        assert_eq!(majors, vec![-0.3, -0.2, -0.1, 0.0, 0.1, 0.2, 0.3]);
+
+        assert!(nice_values(1.0, 1.0, 5).is_empty());
+        assert!(nice_values(0.0, 1.0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_linear_ticks() {
+        let scale: LinearScale<f64> = LinearScale::new(0.0, 10.0);
+        let ticks = scale.ticks(5);
+
+        assert_eq!(majors(&ticks), vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(ticks.len(), 21);
+        assert_approx_eq!(ticks[1].value, 0.5);
+        assert!(!ticks[1].major);
+        for tick in &ticks {
+            assert_approx_eq!(tick.relative, tick.value / 10.0);
+        }
+
+        let inverted: LinearScale<f64> = LinearScale::inverted(0.0, 10.0);
+        let ticks = inverted.ticks(5);
+        assert_eq!(ticks[0].value, 10.0);
+        assert_eq!(ticks[0].relative, 0.0);
+    }
+
+    #[test]
+    fn test_integral_ticks() {
+        let scale: LinearScale<i32> = LinearScale::new(0, 4);
+        let ticks = scale.ticks(10);
+        assert_eq!(
+            ticks.iter().map(|t| t.value).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_log_ticks() {
+        let scale = LogarithmicScale::new(20.0, 20_000.0);
+
+        let ticks = scale.ticks(3);
+        assert_eq!(majors(&ticks), vec![100.0, 1000.0, 10_000.0]);
+        assert_eq!(ticks[0].value, 20.0);
+        assert!(!ticks[0].major);
+        assert_approx_eq!(ticks[0].relative, 0.0);
+
+        let ticks = scale.ticks(10);
+        assert_eq!(
+            majors(&ticks),
+            vec![20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0, 5000.0, 10_000.0, 20_000.0]
+        );
+
+        let wide = LogarithmicScale::new(1e-3, 1e9);
+        assert_eq!(majors(&wide.ticks(4)), vec![1e-3, 1.0, 1e3, 1e6, 1e9]);
+
+        let narrow = LogarithmicScale::new(100.0, 120.0);
+        assert_eq!(
+            majors(&narrow.ticks(4)),
+            vec![100.0, 105.0, 110.0, 115.0, 120.0]
+        );
+    }
+
+    #[test]
+    fn test_broken_ticks() {
+        let scale = BrokenScale::new(-120.0, 12.0, &[(-60.0, 0.25), (-20.0, 0.5)]);
+        let ticks = scale.ticks(8);
+
+        let majors = majors(&ticks);
+        assert!(majors.contains(&-120.0));
+        assert!(majors.contains(&-60.0));
+        assert!(majors.contains(&-20.0));
+        assert!(majors.contains(&12.0));
+        assert!(majors.contains(&0.0));
+
+        for tick in &ticks {
+            assert_approx_eq!(tick.relative, scale.to_relative(tick.value));
+        }
+        assert!(ticks.windows(2).all(|w| w[0].relative < w[1].relative));
+    }
+
+    #[test]
+    fn test_fader_ticks() {
+        let fader: FaderScale<f64> = FaderScale::console_fader();
+        let ticks = fader.ticks(8);
+
+        assert_eq!(ticks[0].value, 0.0);
+        assert_eq!(ticks[0].relative, 0.0);
+        assert!(ticks
+            .iter()
+            .any(|tick| tick.major && (tick.value - 1.0).abs() < 1e-9 && tick.relative == 0.75));
+    }
+
+    #[test]
+    fn test_forwarded_ticks() {
+        let scale: DynScale<f64> = Box::new(LogarithmicScale::new(10.0, 1000.0));
+        assert_eq!(majors(&scale.ticks(2)), vec![10.0, 100.0, 1000.0]);
+
+        let any: AnyScale<f64> = LogarithmicScale::new(10.0, 1000.0).into();
+        assert_eq!(any.ticks(2), scale.ticks(2));
+    }
+}